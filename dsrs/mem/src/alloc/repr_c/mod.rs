@@ -5,8 +5,8 @@ pub use field_tuple::FieldTuple;
 
 use self::field_tuple::layout_with_last_field;
 use super::layout::{Layout, Result};
-use super::{grow, shrink, NonNull};
-use std::alloc::handle_alloc_error;
+use super::{grow_with, shrink_with, NonNull};
+use std::alloc::{handle_alloc_error, AllocRef, Global};
 
 /// The starting fields of a `#[repr(C)]` struct `T`.
 ///
@@ -202,39 +202,99 @@ pub unsafe trait ReprC {
     #[must_use]
     #[inline]
     fn expand(last: Box<Last<Self>>, fields: Start<Self>) -> Box<Self> {
-        let last_ptr = Box::into_raw(last);
+        // SAFETY: a box is never null
+        let last = unsafe { NonNull::new_unchecked(Box::into_raw(last)) };
+        // SAFETY: a box must always be allocated via the global allocator
+        let this = unsafe { Self::expand_in(last, fields, &mut Global) };
+        // SAFETY: `this` is a valid `Self` allocated via the global allocator
+        unsafe { Box::from_raw(this.as_ptr()) }
+    }
 
-        // SAFETY: since `last` is a reference, it is a valid pointer to `Last`
+    /// Expands a pointer to the last field allocated with `alloc` into a
+    /// pointer to the full struct, allocated with the same allocator.
+    ///
+    /// This is the same as [`expand`](ReprC::expand), but for memory that
+    /// is not managed by a [`Box`], so any [`AllocRef`] can be used. The
+    /// memory is reallocated with [`grow_with`].
+    ///
+    /// # Safety
+    ///
+    /// * `last` must point to an initialized [`Last<Self>`](Last),
+    ///   *currently allocated* via `alloc`.
+    /// * The allocated block must *fit* `Layout::for_value(last)`.
+    ///
+    /// On return, ownership of `last` has been moved to the returned pointer,
+    /// whose block fits `Layout::for_value(self)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #![feature(allocator_api)]
+    /// use mem::alloc::ReprC;
+    /// use mem::ptr::NonNull;
+    /// use std::alloc::Global;
+    ///
+    /// #[repr(C)]
+    /// struct Unique<T: ?Sized> {
+    ///     id: usize,
+    ///     data: T
+    /// }
+    ///
+    /// // SAFETY: Unique<T> is a repr(C) struct
+    /// unsafe impl<T: ?Sized> ReprC for Unique<T> {
+    ///     type Fields = (usize, T);
+    /// }
+    ///
+    /// let data = Box::into_raw(vec![1u8, 2, 3].into_boxed_slice());
+    /// // SAFETY: the box was allocated via the global allocator
+    /// let unique = unsafe {
+    ///     Unique::expand_in(NonNull::new_unchecked(data), (5,), &mut Global)
+    /// };
+    ///
+    /// // SAFETY: `unique` is valid and was allocated via the global allocator
+    /// let (data, (id,)) = unsafe { Unique::split_in(unique, true, &mut Global) };
+    /// assert_eq!(id, 5);
+    /// // SAFETY: since it was shrunk, it fits a `Box<[u8]>`
+    /// assert_eq!(*unsafe { Box::from_raw(data.as_ptr()) }, [1, 2, 3])
+    /// ```
+    #[must_use]
+    #[inline]
+    unsafe fn expand_in<A: AllocRef>(
+        last: NonNull<Last<Self>>,
+        fields: Start<Self>,
+        alloc: &mut A,
+    ) -> NonNull<Self> {
+        let last_ptr = last.as_ptr();
+
+        // SAFETY: the caller guarantees that `last` is a valid pointer to `Last`
         let (self_layout, last_layout) =
             match unsafe { layout_with_last_field::<Self::Fields>(last_ptr) } {
-                // SAFETY: again, valid reference
+                // SAFETY: again, valid pointer
                 Err(_) => handle_alloc_error(unsafe { Layout::for_value_raw(last_ptr).inner() }),
                 Ok((layout, _, last)) => (layout, last),
             };
 
-        // SAFETY: a box must always be allocated via the global allocator and the
-        // poiner was nonnull
-        let (self_ptr, last_ptr) =
-            match unsafe { grow(NonNull::new_unchecked(last_ptr), last_layout, self_layout) } {
-                Err(_) => handle_alloc_error(self_layout.inner()),
-                // SAFETY: pointer now can hold a Self, but its uninitialized,
-                // the metadata must also be the same, as Last is its last field
-                Ok(ptr) => (unsafe { ptr.cast_unsized::<Self>() }.as_ptr(), ptr.as_ptr()),
-            };
+        // SAFETY: the caller guarantees that `last` was allocated via `alloc`
+        // and the last field is never larger than the struct
+        let (self_ptr, last_ptr) = match unsafe { grow_with(last, last_layout, self_layout, alloc) } {
+            Err(_) => handle_alloc_error(self_layout.inner()),
+            // SAFETY: pointer now can hold a Self, but its uninitialized,
+            // the metadata must also be the same, as Last is its last field
+            Ok(ptr) => (unsafe { ptr.cast_unsized::<Self>() }, ptr.as_ptr()),
+        };
 
         // SAFETY: self_ptr can hold Self, which is a repr(C) with Self::Fields
-        // also `last` is valid since it was a reference
+        // also `last` is valid since the caller guarantees it
         unsafe {
             // move the last field to the end
-            Self::Fields::write_last(self_ptr as *mut u8, last_ptr);
+            Self::Fields::write_last(self_ptr.as_ptr() as *mut u8, last_ptr);
             // insert starting fields
-            Self::Fields::write_start(self_ptr as *mut u8, fields);
+            Self::Fields::write_start(self_ptr.as_ptr() as *mut u8, fields);
         }
-        // SAFETY: self_ptr is now valid and initialized reference to Self
-        let this = unsafe { Box::from_raw(self_ptr) };
         // check validity of layouts
-        debug_assert!(self_layout == Layout::for_value(this.as_ref()));
-        this
+        // SAFETY: self_ptr is now a valid and initialized Self
+        debug_assert!(self_layout == unsafe { Layout::for_value_raw(self_ptr.as_ptr()) });
+        self_ptr
     }
 
     /// Splits a boxed `Self` into its first fields and the last field.
@@ -292,8 +352,43 @@ pub unsafe trait ReprC {
     #[must_use]
     #[inline]
     fn split(self: Box<Self>, try_shrink: bool) -> (Box<Last<Self>>, Start<Self>) {
-        let self_layout = Layout::for_value(self.as_ref());
-        let self_ptr = Box::into_raw(self);
+        // SAFETY: a box is never null
+        let this = unsafe { NonNull::new_unchecked(Box::into_raw(self)) };
+        // SAFETY: a box must always be allocated via the global allocator
+        let (last, start) = unsafe { Self::split_in(this, try_shrink, &mut Global) };
+        // SAFETY: last now points to the start of of the last field, shrunk or not
+        (unsafe { Box::from_raw(last.as_ptr()) }, start)
+    }
+
+    /// Splits a pointer to `Self` allocated with `alloc` into its first
+    /// fields and a pointer to the last field, in the same allocation.
+    ///
+    /// This is the same as [`split`](ReprC::split), but for memory that
+    /// is not managed by a [`Box`], so any [`AllocRef`] can be used. When
+    /// `try_shrink` is `true`, the memory is reallocated with
+    /// [`shrink_with`].
+    ///
+    /// # Safety
+    ///
+    /// * `this` must point to an initialized `Self`, *currently allocated*
+    ///   via `alloc`.
+    /// * The allocated block must *fit* `Layout::for_value(this)`.
+    ///
+    /// On return, ownership of `this` has been moved to the returned pointer.
+    /// When `try_shrink` is `true`, its block fits `Layout::for_value(last)`,
+    /// otherwise the block still fits the layout of `Self`.
+    ///
+    /// See [`expand_in`](ReprC::expand_in) for an example.
+    #[must_use]
+    #[inline]
+    unsafe fn split_in<A: AllocRef>(
+        this: NonNull<Self>,
+        try_shrink: bool,
+        alloc: &mut A,
+    ) -> (NonNull<Last<Self>>, Start<Self>) {
+        let self_ptr = this.as_ptr();
+        // SAFETY: the caller guarantees that `this` is valid
+        let self_layout = unsafe { Layout::for_value_raw(self_ptr) };
         // SAFETY: since self is initialized, it can hold its Last field
         // but the pointer might be to another field
         let mut last_ptr = unsafe { *(&self_ptr as *const _ as *const *mut Last<Self>) };
@@ -319,20 +414,20 @@ pub unsafe trait ReprC {
         unsafe { Self::Fields::read_last(self_ptr as *const u8, last_ptr) };
 
         if try_shrink {
-            // SAFETY: a box must always be allocated via the global allocator and the
-            // poiner was nonnull
-            match unsafe { shrink(NonNull::new_unchecked(self_ptr), self_layout, last_layout) } {
+            // SAFETY: the caller guarantees that `this` was allocated via `alloc`
+            match unsafe { shrink_with(this, self_layout, last_layout, alloc) } {
                 Err(_) => handle_alloc_error(last_layout.inner()),
                 Ok(new) => last_ptr = unsafe { new.cast_unsized().as_ptr() },
             }
         } else {
             debug_assert!(self_layout.align() >= last_layout.align())
         }
-        // SAFETY: last_ptr now points to the start of of the last field, shrunk or not
-        let last = unsafe { Box::from_raw(last_ptr) };
+        // SAFETY: last_ptr is never null, since it comes from `this`
+        let last = unsafe { NonNull::new_unchecked(last_ptr) };
 
         // check validity of layouts
-        debug_assert!(last_layout == Layout::for_value(last.as_ref()));
+        // SAFETY: last now points to the start of of the last field, shrunk or not
+        debug_assert!(last_layout == unsafe { Layout::for_value_raw(last.as_ptr()) });
         (last, start)
     }
 }
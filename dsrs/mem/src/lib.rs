@@ -17,6 +17,9 @@
 //! [`Box<Last<Self>>`](alloc::Last) with the last field. The trait can
 //! also break apart the structure still on the heap, returning each of
 //! its fields.
+//! The same can be done with memory from any allocator, using
+//! [`expand_in`](alloc::ReprC::expand_in) and
+//! [`split_in`](alloc::ReprC::split_in).
 //!
 //! # Wrappers
 //!
@@ -0,0 +1,157 @@
+use crate::lists::LinkedList;
+use crate::queue::Deque;
+use crate::vec::{GrowthPolicy, Vector};
+
+use std::alloc::AllocRef;
+
+/// Storage that can back a [`Stack`](super::Stack) or a
+/// [`Queue`](super::Queue).
+///
+/// The top of the stack is whichever end the container handles best, while
+/// the queue always goes from tail to head.
+pub trait Container<T> {
+    fn len(&self) -> usize;
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn push_top(&mut self, data: T);
+    fn pop_top(&mut self) -> Option<T>;
+    fn top(&self) -> Option<&T>;
+    fn top_mut(&mut self) -> Option<&mut T>;
+
+    fn push_tail(&mut self, data: T);
+    fn pop_head(&mut self) -> Option<T>;
+    fn head(&self) -> Option<&T>;
+}
+
+/// The top is the head, since popping the tail takes linear time.
+impl<T, A: AllocRef> Container<T> for LinkedList<T, A> {
+    #[inline]
+    fn len(&self) -> usize {
+        Self::len(self)
+    }
+
+    #[inline]
+    fn push_top(&mut self, data: T) {
+        self.push_head(data)
+    }
+
+    #[inline]
+    fn pop_top(&mut self) -> Option<T> {
+        self.pop()
+    }
+
+    #[inline]
+    fn top(&self) -> Option<&T> {
+        Self::head(self)
+    }
+
+    #[inline]
+    fn top_mut(&mut self) -> Option<&mut T> {
+        self.head_mut()
+    }
+
+    #[inline]
+    fn push_tail(&mut self, data: T) {
+        Self::push_tail(self, data)
+    }
+
+    #[inline]
+    fn pop_head(&mut self) -> Option<T> {
+        self.pop()
+    }
+
+    #[inline]
+    fn head(&self) -> Option<&T> {
+        Self::head(self)
+    }
+}
+
+/// The top is the last element, and popping the head takes linear time.
+impl<T, P: GrowthPolicy> Container<T> for Vector<T, P> {
+    #[inline]
+    fn len(&self) -> usize {
+        Self::len(self)
+    }
+
+    #[inline]
+    fn push_top(&mut self, data: T) {
+        self.push(data)
+    }
+
+    #[inline]
+    fn pop_top(&mut self) -> Option<T> {
+        self.pop()
+    }
+
+    #[inline]
+    fn top(&self) -> Option<&T> {
+        self.last()
+    }
+
+    #[inline]
+    fn top_mut(&mut self) -> Option<&mut T> {
+        self.last_mut()
+    }
+
+    #[inline]
+    fn push_tail(&mut self, data: T) {
+        self.push(data)
+    }
+
+    #[inline]
+    fn pop_head(&mut self) -> Option<T> {
+        self.remove(0)
+    }
+
+    #[inline]
+    fn head(&self) -> Option<&T> {
+        self.first()
+    }
+}
+
+/// The top is the tail.
+impl<T> Container<T> for Deque<T> {
+    #[inline]
+    fn len(&self) -> usize {
+        Self::len(self)
+    }
+
+    #[inline]
+    fn push_top(&mut self, data: T) {
+        Self::push_tail(self, data)
+    }
+
+    #[inline]
+    fn pop_top(&mut self) -> Option<T> {
+        self.pop_tail()
+    }
+
+    #[inline]
+    fn top(&self) -> Option<&T> {
+        self.tail()
+    }
+
+    #[inline]
+    fn top_mut(&mut self) -> Option<&mut T> {
+        self.tail_mut()
+    }
+
+    #[inline]
+    fn push_tail(&mut self, data: T) {
+        Self::push_tail(self, data)
+    }
+
+    #[inline]
+    fn pop_head(&mut self) -> Option<T> {
+        Self::pop_head(self)
+    }
+
+    #[inline]
+    fn head(&self) -> Option<&T> {
+        Self::head(self)
+    }
+}
@@ -0,0 +1,9 @@
+//! Stacks and queues over any [`Container`], with the interfaces from
+//! `pilha.h` and `fila.h`.
+mod container;
+mod stack;
+mod queue;
+
+pub use container::Container;
+pub use stack::Stack;
+pub use queue::Queue;
@@ -0,0 +1,153 @@
+use super::container::Container;
+use crate::queue::Deque;
+
+use std::fmt::{Debug, Formatter, Result};
+use std::marker::PhantomData;
+
+/// A queue like the one from `fila.h`, over any [`Container`].
+pub struct Queue<T, C: Container<T> = Deque<T>> {
+    container: C,
+    marker: PhantomData<T>
+}
+
+impl<T, C: Container<T>> Queue<T, C> {
+    #[must_use]
+    #[inline]
+    pub fn new() -> Self
+    where
+        C: Default
+    {
+        Self::with_container(C::default())
+    }
+
+    /// The current elements of `container` are already in the queue, from
+    /// head to tail.
+    #[must_use]
+    #[inline]
+    pub const fn with_container(container: C) -> Self {
+        Self { container, marker: PhantomData }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> C {
+        self.container
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.container.len()
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.container.is_empty()
+    }
+
+    #[inline]
+    pub fn enfileirar(&mut self, dado: T) {
+        self.container.push_tail(dado)
+    }
+
+    #[inline]
+    pub fn desenfileirar(&mut self) -> Option<T> {
+        self.container.pop_head()
+    }
+
+    /// The next element to leave the queue.
+    #[must_use]
+    #[inline]
+    pub fn ver_primeiro(&self) -> Option<&T> {
+        self.container.head()
+    }
+
+    /// Updates every element in place, from head to tail.
+    #[inline]
+    pub fn atualizar_dados<F: FnMut(&mut T)>(&mut self, atualiza: F)
+    where
+        for<'a> &'a mut C: IntoIterator<Item = &'a mut T>
+    {
+        (&mut self.container).into_iter().for_each(atualiza)
+    }
+}
+
+impl<T, C: Container<T> + Default> Default for Queue<T, C> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, C: Container<T> + Debug> Debug for Queue<T, C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_tuple("Queue").field(&self.container).finish()
+    }
+}
+
+impl<T, C: Container<T> + Clone> Clone for Queue<T, C> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::with_container(self.container.clone())
+    }
+}
+
+impl<T, C: Container<T>> Extend<T> for Queue<T, C> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for dado in iter {
+            self.enfileirar(dado)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::Container;
+    use super::Queue;
+    use crate::lists::LinkedList;
+    use crate::vec::Vector;
+
+    /// Round robin with `quantum` turns, like the scheduler from Lab04,
+    /// returning the order in which each task finishes.
+    fn round_robin<C>(tasks: &[(char, u32)], quantum: u32) -> String
+    where
+        C: Container<(char, u32)> + Default,
+        for<'a> &'a mut C: IntoIterator<Item = &'a mut (char, u32)>
+    {
+        let mut fila: Queue<_, C> = Queue::new();
+        fila.extend(tasks.iter().copied());
+        // everyone waits a turn before starting
+        fila.atualizar_dados(|(_, left)| *left += 1);
+
+        let mut finished = String::new();
+        while let Some((name, left)) = fila.desenfileirar() {
+            if left <= quantum {
+                finished.push(name)
+            } else {
+                fila.enfileirar((name, left - quantum))
+            }
+        }
+        finished
+    }
+
+    #[test]
+    fn any_backend() {
+        let tasks = [('a', 5), ('b', 1), ('c', 3), ('d', 8)];
+        assert_eq!(round_robin::<crate::queue::Deque<_>>(&tasks, 2), "bcad");
+        assert_eq!(round_robin::<LinkedList<_>>(&tasks, 2), "bcad");
+        assert_eq!(round_robin::<Vector<_>>(&tasks, 2), "bcad")
+    }
+
+    #[test]
+    fn first() {
+        let mut fila: Queue<_> = Queue::new();
+        assert_eq!(fila.ver_primeiro(), None);
+        fila.extend(vec!["x", "y"]);
+        assert_eq!(fila.ver_primeiro(), Some(&"x"));
+        assert_eq!(fila.len(), 2);
+        assert_eq!(fila.clone().desenfileirar(), Some("x"));
+        assert_eq!(format!("{:?}", fila), r#"Queue(["x", "y"])"#)
+    }
+}
@@ -0,0 +1,154 @@
+use super::container::Container;
+use crate::vec::Vector;
+
+use std::fmt::{Debug, Formatter, Result};
+use std::marker::PhantomData;
+
+/// A stack like the one from `pilha.h`, over any [`Container`].
+pub struct Stack<T, C: Container<T> = Vector<T>> {
+    container: C,
+    marker: PhantomData<T>
+}
+
+impl<T, C: Container<T>> Stack<T, C> {
+    #[must_use]
+    #[inline]
+    pub fn new() -> Self
+    where
+        C: Default
+    {
+        Self::with_container(C::default())
+    }
+
+    /// The current elements of `container` are already in the stack.
+    #[must_use]
+    #[inline]
+    pub const fn with_container(container: C) -> Self {
+        Self { container, marker: PhantomData }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> C {
+        self.container
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.container.len()
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.container.is_empty()
+    }
+
+    #[inline]
+    pub fn empilhar(&mut self, dado: T) {
+        self.container.push_top(dado)
+    }
+
+    #[inline]
+    pub fn desempilhar(&mut self) -> Option<T> {
+        self.container.pop_top()
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn ver_topo(&self) -> Option<&T> {
+        self.container.top()
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn ver_topo_mut(&mut self) -> Option<&mut T> {
+        self.container.top_mut()
+    }
+}
+
+impl<T, C: Container<T> + Default> Default for Stack<T, C> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, C: Container<T> + Debug> Debug for Stack<T, C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_tuple("Stack").field(&self.container).finish()
+    }
+}
+
+impl<T, C: Container<T> + Clone> Clone for Stack<T, C> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::with_container(self.container.clone())
+    }
+}
+
+impl<T, C: Container<T>> Extend<T> for Stack<T, C> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for dado in iter {
+            self.empilhar(dado)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::Container;
+    use super::Stack;
+    use crate::lists::LinkedList;
+    use crate::queue::Deque;
+
+    /// Checks the brackets in `text`, like Lab05.
+    fn balanced<C: Container<char> + Default>(text: &str) -> bool {
+        let mut pilha: Stack<char, C> = Stack::new();
+        for ch in text.chars() {
+            match ch {
+                '(' | '[' | '{' => pilha.empilhar(ch),
+                ')' | ']' | '}' => {
+                    let expected = match ch {
+                        ')' => '(',
+                        ']' => '[',
+                        _ => '{'
+                    };
+                    if pilha.desempilhar() != Some(expected) {
+                        return false
+                    }
+                },
+                _ => ()
+            }
+        }
+        pilha.is_empty()
+    }
+
+    #[test]
+    fn any_backend() {
+        for &(text, expected) in &[("{a[b(c)]d}()", true), ("([)]", false), ("((", false)] {
+            assert_eq!(balanced::<crate::vec::Vector<_>>(text), expected);
+            assert_eq!(balanced::<LinkedList<_>>(text), expected);
+            assert_eq!(balanced::<Deque<_>>(text), expected)
+        }
+    }
+
+    #[test]
+    fn top() {
+        let mut pilha: Stack<_, LinkedList<_>> = Stack::new();
+        assert_eq!(pilha.ver_topo(), None);
+        pilha.extend(1..=3);
+        *pilha.ver_topo_mut().unwrap() *= 10;
+        assert_eq!(pilha.ver_topo(), Some(&30));
+        assert_eq!(pilha.len(), 3);
+
+        let mut inner = pilha.into_inner();
+        assert_eq!(inner.pop(), Some(30));
+        let mut pilha = Stack::with_container(inner);
+        assert_eq!(pilha.desempilhar(), Some(2));
+        assert_eq!(pilha.desempilhar(), Some(1));
+        assert_eq!(pilha.desempilhar(), None)
+    }
+}
@@ -0,0 +1,376 @@
+use super::compare::{Compare, MaxFirst};
+use crate::vec::{IntoIter, Vector};
+
+use std::cmp::Ordering;
+use std::fmt::{Debug, Formatter, Result};
+use std::iter::FromIterator;
+use std::ops::{Deref, DerefMut};
+use std::slice;
+
+/// A priority queue in an implicit binary tree, like `FilaPrio` from Lab06.
+///
+/// The greatest element, according to the comparator, is at the root.
+pub struct BinaryHeap<T, C: Compare<T> = MaxFirst> {
+    items: Vector<T>,
+    cmp: C
+}
+
+impl<T: Ord> BinaryHeap<T> {
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self::with_comparator(MaxFirst)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self { items: Vector::with_capacity(capacity), cmp: MaxFirst }
+    }
+}
+
+impl<T, C: Compare<T>> BinaryHeap<T, C> {
+    #[must_use]
+    #[inline]
+    pub const fn with_comparator(cmp: C) -> Self {
+        Self { items: Vector::new(), cmp }
+    }
+
+    /// Builds the heap in linear time.
+    #[must_use]
+    #[inline]
+    pub fn from_vector(items: Vector<T>, cmp: C) -> Self {
+        let mut heap = Self { items, cmp };
+        heap.rebuild();
+        heap
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn comparator(&self) -> &C {
+        &self.cmp
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// The elements in no particular order.
+    #[must_use]
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        self.items.as_slice()
+    }
+
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.items.iter()
+    }
+
+    #[inline]
+    fn greater(&self, i: usize, j: usize) -> bool {
+        self.cmp.compare(&self.items[i], &self.items[j]) == Ordering::Greater
+    }
+
+    /// Moves the element at `index` up while it is greater than its parent.
+    fn sift_up(&mut self, mut index: usize) {
+        while index > 0 {
+            let parent = (index - 1) / 2;
+            if !self.greater(index, parent) {
+                break
+            }
+            self.items.swap(index, parent);
+            index = parent
+        }
+    }
+
+    /// Moves the element at `index` down while it is smaller than one of
+    /// its children, considering only the first `end` elements.
+    fn sift_down(&mut self, mut index: usize, end: usize) {
+        loop {
+            let left = 2 * index + 1;
+            if left >= end {
+                break
+            }
+            let right = left + 1;
+            let child = if right < end && self.greater(right, left) { right } else { left };
+            if !self.greater(child, index) {
+                break
+            }
+            self.items.swap(index, child);
+            index = child
+        }
+    }
+
+    /// Restores the heap property from the bottom up, in linear time.
+    fn rebuild(&mut self) {
+        let len = self.len();
+        for index in (0..len / 2).rev() {
+            self.sift_down(index, len)
+        }
+    }
+
+    #[inline]
+    pub fn push(&mut self, item: T) {
+        self.items.push(item);
+        self.sift_up(self.len() - 1)
+    }
+
+    /// Removes the greatest element.
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        let last = self.len().checked_sub(1)?;
+        self.items.swap(0, last);
+        let item = self.items.pop();
+        self.sift_down(0, last);
+        item
+    }
+
+    /// The greatest element.
+    #[must_use]
+    #[inline]
+    pub fn peek(&self) -> Option<&T> {
+        self.items.first()
+    }
+
+    /// The greatest element, which goes back to its place once the guard
+    /// is dropped.
+    #[must_use]
+    #[inline]
+    pub fn peek_mut(&mut self) -> Option<PeekMut<'_, T, C>> {
+        if self.is_empty() { None } else { Some(PeekMut { heap: self }) }
+    }
+
+    /// Moves every element from `other`, leaving it empty.
+    pub fn append(&mut self, other: &mut Self) {
+        if other.len() > self.len() {
+            std::mem::swap(&mut self.items, &mut other.items)
+        }
+        let (start, added) = (self.len(), other.len());
+        self.items.extend(std::mem::replace(&mut other.items, Vector::new()));
+        // rebuilding is linear, so it's better unless 'other' is much smaller
+        if added.saturating_mul(2) >= start {
+            self.rebuild()
+        } else {
+            for index in start..self.len() {
+                self.sift_up(index)
+            }
+        }
+    }
+
+    /// The elements in ascending order, in `O(n log n)` time.
+    #[must_use]
+    pub fn into_sorted_vec(mut self) -> Vector<T> {
+        for end in (1..self.len()).rev() {
+            self.items.swap(0, end);
+            self.sift_down(0, end)
+        }
+        self.items
+    }
+
+    /// The elements in no particular order.
+    #[must_use]
+    #[inline]
+    pub fn into_vector(self) -> Vector<T> {
+        self.items
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.items.clear()
+    }
+}
+
+/// Unique access to the greatest element of a [`BinaryHeap`].
+pub struct PeekMut<'a, T, C: Compare<T>> {
+    heap: &'a mut BinaryHeap<T, C>
+}
+
+impl<T, C: Compare<T>> PeekMut<'_, T, C> {
+    /// Removes the element from the heap.
+    ///
+    /// # Panics
+    ///
+    /// Never, since guards are only made for non-empty heaps.
+    #[must_use]
+    #[inline]
+    pub fn pop(this: Self) -> T {
+        let item = this.heap.pop();
+        // the heap is already in order
+        std::mem::forget(this);
+        item.expect("PeekMut on an empty heap")
+    }
+}
+
+impl<T, C: Compare<T>> Deref for PeekMut<'_, T, C> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.heap.items[0]
+    }
+}
+
+impl<T, C: Compare<T>> DerefMut for PeekMut<'_, T, C> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.heap.items[0]
+    }
+}
+
+impl<T, C: Compare<T>> Drop for PeekMut<'_, T, C> {
+    #[inline]
+    fn drop(&mut self) {
+        let len = self.heap.len();
+        self.heap.sift_down(0, len)
+    }
+}
+
+impl<T: Ord> Default for BinaryHeap<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Debug, C: Compare<T>> Debug for BinaryHeap<T, C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Clone, C: Compare<T> + Clone> Clone for BinaryHeap<T, C> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self { items: self.items.clone(), cmp: self.cmp.clone() }
+    }
+}
+
+impl<T: Ord> From<Vector<T>> for BinaryHeap<T> {
+    #[inline]
+    fn from(items: Vector<T>) -> Self {
+        Self::from_vector(items, MaxFirst)
+    }
+}
+
+impl<T, C: Compare<T> + Default> FromIterator<T> for BinaryHeap<T, C> {
+    /// Builds the heap in linear time.
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from_vector(iter.into_iter().collect(), C::default())
+    }
+}
+
+impl<T, C: Compare<T>> Extend<T> for BinaryHeap<T, C> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let start = self.len();
+        self.items.extend(iter);
+        for index in start..self.len() {
+            self.sift_up(index)
+        }
+    }
+}
+
+impl<T, C: Compare<T>> IntoIterator for BinaryHeap<T, C> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    /// The elements in no particular order.
+    #[inline]
+    fn into_iter(self) -> IntoIter<T> {
+        self.items.into_iter()
+    }
+}
+
+impl<'a, T, C: Compare<T>> IntoIterator for &'a BinaryHeap<T, C> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> slice::Iter<'a, T> {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::MinFirst;
+    use super::{BinaryHeap, PeekMut};
+    use crate::vec::Vector;
+
+    #[test]
+    fn max_heap() {
+        let mut heap = BinaryHeap::new();
+        for &item in &[3, 1, 4, 1, 5, 9, 2, 6] {
+            heap.push(item)
+        }
+        assert_eq!(heap.peek(), Some(&9));
+        assert_eq!(heap.len(), 8);
+
+        let mut popped = Vec::new();
+        while let Some(item) = heap.pop() {
+            popped.push(item)
+        }
+        assert_eq!(popped, vec![9, 6, 5, 4, 3, 2, 1, 1]);
+        assert_eq!(heap.peek(), None)
+    }
+
+    #[test]
+    fn comparators() {
+        let mut heap: BinaryHeap<_, MinFirst> = (1..=10).rev().collect();
+        assert_eq!(heap.pop(), Some(1));
+        assert_eq!(heap.pop(), Some(2));
+
+        // by priority and then by arrival, like the patients from Lab06
+        let by_priority = |a: &(u8, u32), b: &(u8, u32)| a.0.cmp(&b.0).then(b.1.cmp(&a.1));
+        let mut heap = BinaryHeap::with_comparator(by_priority);
+        heap.extend(vec![(1, 0), (3, 1), (2, 2), (3, 3), (1, 4)]);
+        let order: Vec<_> = std::iter::from_fn(|| heap.pop()).map(|(_, id)| id).collect();
+        assert_eq!(order, vec![1, 3, 2, 0, 4])
+    }
+
+    #[test]
+    fn peek_mut() {
+        let mut heap: BinaryHeap<_> = vec![5, 8, 2, 7].into_iter().collect();
+        *heap.peek_mut().unwrap() = 1;
+        assert_eq!(heap.peek(), Some(&7));
+        {
+            let mut top = heap.peek_mut().unwrap();
+            *top += 10;
+        }
+        assert_eq!(heap.peek(), Some(&17));
+
+        let top = heap.peek_mut().unwrap();
+        assert_eq!(PeekMut::pop(top), 17);
+        assert_eq!(heap.into_sorted_vec().as_slice(), &[1, 2, 5])
+    }
+
+    #[test]
+    fn heapify_and_append() {
+        let items: Vector<_> = (0..20).map(|i| (i * 7) % 20).collect();
+        let mut heap = BinaryHeap::from(items);
+        assert_eq!(heap.peek(), Some(&19));
+
+        let mut small: BinaryHeap<_> = vec![100, -1].into_iter().collect();
+        heap.append(&mut small);
+        assert!(small.is_empty());
+        let mut large: BinaryHeap<_> = (20..50).collect();
+        small.append(&mut heap);
+        small.append(&mut large);
+        assert_eq!((small.len(), heap.len(), large.len()), (52, 0, 0));
+
+        let sorted = small.into_sorted_vec();
+        assert_eq!(sorted[0], -1);
+        assert_eq!(sorted[51], 100);
+        assert!(sorted.windows(2).all(|pair| pair[0] <= pair[1]))
+    }
+}
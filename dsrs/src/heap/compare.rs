@@ -0,0 +1,38 @@
+use std::cmp::Ordering;
+
+/// Decides which element leaves a heap first: the greatest one.
+///
+/// Works like the `Comparador` from Lab06, but returning an [`Ordering`].
+pub trait Compare<T: ?Sized> {
+    fn compare(&self, a: &T, b: &T) -> Ordering;
+}
+
+/// Any function from two elements to their [`Ordering`].
+impl<T: ?Sized, F: Fn(&T, &T) -> Ordering> Compare<T> for F {
+    #[inline]
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        self(a, b)
+    }
+}
+
+/// The natural order, making a max-heap.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MaxFirst;
+
+impl<T: ?Sized + Ord> Compare<T> for MaxFirst {
+    #[inline]
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        a.cmp(b)
+    }
+}
+
+/// The reversed order, making a min-heap.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MinFirst;
+
+impl<T: ?Sized + Ord> Compare<T> for MinFirst {
+    #[inline]
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        b.cmp(a)
+    }
+}
@@ -0,0 +1,334 @@
+use crate::vec::Vector;
+
+use std::fmt::{Debug, Formatter, Result};
+
+/// Position of handles that are not in the heap.
+const ABSENT: usize = usize::MAX;
+
+struct Entry<P> {
+    handle: usize,
+    priority: P
+}
+
+/// A min-priority queue of dense `usize` handles, like `FilaPrio` from
+/// Lab12.
+///
+/// Each handle remembers where it is in the heap, so its priority can be
+/// changed or removed in logarithmic time.
+pub struct IndexedHeap<P: Ord> {
+    heap: Vector<Entry<P>>,
+    // position of each handle in 'heap'
+    index: Vector<usize>
+}
+
+impl<P: Ord> IndexedHeap<P> {
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self { heap: Vector::new(), index: Vector::new() }
+    }
+
+    /// Room for the handles in `0..handles`.
+    #[must_use]
+    #[inline]
+    pub fn with_capacity(handles: usize) -> Self {
+        let mut index = Vector::with_capacity(handles);
+        index.extend((0..handles).map(|_| ABSENT));
+        Self { heap: Vector::with_capacity(handles), index }
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    #[inline]
+    fn position(&self, handle: usize) -> Option<usize> {
+        self.index.get(handle).copied().filter(|&pos| pos != ABSENT)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn contains(&self, handle: usize) -> bool {
+        self.position(handle).is_some()
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn priority(&self, handle: usize) -> Option<&P> {
+        let pos = self.position(handle)?;
+        Some(&self.heap[pos].priority)
+    }
+
+    /// The handle with the smallest priority.
+    #[must_use]
+    #[inline]
+    pub fn peek_min(&self) -> Option<(usize, &P)> {
+        self.heap.first().map(|entry| (entry.handle, &entry.priority))
+    }
+
+    #[inline]
+    fn swap(&mut self, i: usize, j: usize) {
+        self.heap.swap(i, j);
+        self.index[self.heap[i].handle] = i;
+        self.index[self.heap[j].handle] = j
+    }
+
+    #[inline]
+    fn less(&self, i: usize, j: usize) -> bool {
+        self.heap[i].priority < self.heap[j].priority
+    }
+
+    /// Checks that the entry at `pos` is in order with its parent and
+    /// children, and that its handle knows where it is.
+    #[inline]
+    fn check(&self, pos: usize) {
+        if cfg!(debug_assertions) && pos < self.len() {
+            let parent = pos == 0 || !self.less(pos, (pos - 1) / 2);
+            let children = (2 * pos + 1..self.len().min(2 * pos + 3)).all(|child| !self.less(child, pos));
+            let handle = self.index[self.heap[pos].handle] == pos;
+            // SAFETY: only checked in debug builds, where 'assume!' panics
+            // when the condition is false
+            unsafe { hint::assume!(parent && children && handle) }
+        }
+    }
+
+    /// Moves the entry at `pos` up while it is smaller than its parent,
+    /// like `subir_no_heap`.
+    fn sift_up(&mut self, mut pos: usize) {
+        while pos > 0 {
+            let parent = (pos - 1) / 2;
+            if !self.less(pos, parent) {
+                break
+            }
+            self.swap(pos, parent);
+            pos = parent
+        }
+        self.check(pos)
+    }
+
+    /// Moves the entry at `pos` down while it is greater than one of its
+    /// children, like `descer_no_heap`.
+    fn sift_down(&mut self, mut pos: usize) {
+        loop {
+            let left = 2 * pos + 1;
+            if left >= self.len() {
+                break
+            }
+            let right = left + 1;
+            let child = if right < self.len() && self.less(right, left) { right } else { left };
+            if !self.less(child, pos) {
+                break
+            }
+            self.swap(pos, child);
+            pos = child
+        }
+        self.check(pos)
+    }
+
+    /// Restores the order after the priority at `pos` changed either way.
+    #[inline]
+    fn sift(&mut self, pos: usize) {
+        if pos > 0 && self.less(pos, (pos - 1) / 2) {
+            self.sift_up(pos)
+        } else {
+            self.sift_down(pos)
+        }
+    }
+
+    /// Adds `handle` to the heap, or changes its priority if it is already
+    /// there, returning the old one.
+    pub fn insert(&mut self, handle: usize, priority: P) -> Option<P> {
+        if let Some(pos) = self.position(handle) {
+            let old = std::mem::replace(&mut self.heap[pos].priority, priority);
+            self.sift(pos);
+            return Some(old)
+        }
+        if handle >= self.index.len() {
+            let missing = handle + 1 - self.index.len();
+            self.index.extend((0..missing).map(|_| ABSENT))
+        }
+        self.index[handle] = self.len();
+        self.heap.push(Entry { handle, priority });
+        self.sift_up(self.len() - 1);
+        None
+    }
+
+    /// Removes the entry at `pos`, replacing it with the last one.
+    fn remove_at(&mut self, pos: usize) -> Option<(usize, P)> {
+        let last = self.len().checked_sub(1)?;
+        self.swap(pos, last);
+        let Entry { handle, priority } = self.heap.pop()?;
+        self.index[handle] = ABSENT;
+        if pos < self.len() {
+            self.sift(pos)
+        }
+        Some((handle, priority))
+    }
+
+    /// Removes the handle with the smallest priority, like `extrair_minimo`.
+    #[inline]
+    pub fn extract_min(&mut self) -> Option<(usize, P)> {
+        self.remove_at(0)
+    }
+
+    #[inline]
+    pub fn remove(&mut self, handle: usize) -> Option<P> {
+        let pos = self.position(handle)?;
+        self.remove_at(pos).map(|(_, priority)| priority)
+    }
+
+    /// Lowers the priority of `handle`, like `diminuir_prioridade`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `handle` is not in the heap or if `priority` is greater
+    /// than the current one.
+    pub fn decrease_key(&mut self, handle: usize, priority: P) {
+        let pos = self.position(handle).expect("handle should be in the heap");
+        assert!(priority <= self.heap[pos].priority, "priority should not increase");
+        self.heap[pos].priority = priority;
+        self.sift_up(pos)
+    }
+
+    /// Raises the priority of `handle`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `handle` is not in the heap or if `priority` is smaller
+    /// than the current one.
+    pub fn increase_key(&mut self, handle: usize, priority: P) {
+        let pos = self.position(handle).expect("handle should be in the heap");
+        assert!(priority >= self.heap[pos].priority, "priority should not decrease");
+        self.heap[pos].priority = priority;
+        self.sift_down(pos)
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        for entry in &self.heap {
+            self.index[entry.handle] = ABSENT
+        }
+        self.heap.clear()
+    }
+}
+
+impl<P: Ord> Default for IndexedHeap<P> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P: Ord + Debug> Debug for IndexedHeap<P> {
+    /// Handles and their priorities in heap order.
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let entries = self.heap.iter().map(|entry| (entry.handle, &entry.priority));
+        f.debug_map().entries(entries).finish()
+    }
+}
+
+impl<P: Ord + Clone> Clone for IndexedHeap<P> {
+    #[inline]
+    fn clone(&self) -> Self {
+        let mut heap = Vector::with_capacity(self.len());
+        for Entry { handle, priority } in &self.heap {
+            heap.push(Entry { handle: *handle, priority: priority.clone() })
+        }
+        Self { heap, index: self.index.clone() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IndexedHeap;
+
+    #[test]
+    fn operations() {
+        let mut heap = IndexedHeap::new();
+        for (handle, &priority) in [50, 20, 70, 10, 40].iter().enumerate() {
+            assert_eq!(heap.insert(handle, priority), None)
+        }
+        assert_eq!(heap.peek_min(), Some((3, &10)));
+        assert_eq!(heap.len(), 5);
+
+        heap.decrease_key(2, 5);
+        assert_eq!(heap.peek_min(), Some((2, &5)));
+        heap.increase_key(2, 60);
+        assert_eq!(heap.priority(2), Some(&60));
+        assert_eq!(heap.insert(0, 15), Some(50));
+        assert_eq!(heap.remove(4), Some(40));
+        assert_eq!(heap.remove(4), None);
+        assert!(!heap.contains(4) && !heap.contains(100));
+
+        let mut order = Vec::new();
+        while let Some(min) = heap.extract_min() {
+            order.push(min)
+        }
+        assert_eq!(order, vec![(3, 10), (0, 15), (1, 20), (2, 60)]);
+        assert_eq!(heap.priority(3), None)
+    }
+
+    /// Shortest distances from vertex 0, like the Dijkstra from Lab12.
+    fn dijkstra(edges: &[(usize, usize, u32)], vertices: usize) -> Vec<Option<u32>> {
+        let mut distance = vec![None; vertices];
+        let mut heap = IndexedHeap::with_capacity(vertices);
+        heap.insert(0, 0);
+        while let Some((vertex, dist)) = heap.extract_min() {
+            distance[vertex] = Some(dist);
+            for &(_, next, weight) in edges.iter().filter(|edge| edge.0 == vertex) {
+                if distance[next].is_some() {
+                    continue
+                }
+                match heap.priority(next) {
+                    Some(&old) if old <= dist + weight => (),
+                    Some(_) => heap.decrease_key(next, dist + weight),
+                    None => {
+                        heap.insert(next, dist + weight);
+                    }
+                }
+            }
+        }
+        distance
+    }
+
+    #[test]
+    fn shortest_paths() {
+        let edges = [(0, 1, 4), (0, 2, 1), (2, 1, 2), (1, 3, 1), (2, 3, 5), (3, 4, 3)];
+        let distance = dijkstra(&edges, 6);
+        assert_eq!(distance, vec![Some(0), Some(3), Some(1), Some(4), Some(7), None])
+    }
+
+    #[test]
+    fn removals_keep_order() {
+        let mut heap = IndexedHeap::with_capacity(64);
+        for handle in 0..64 {
+            heap.insert(handle, (handle * 37) % 64);
+        }
+        for handle in (0..64).step_by(3) {
+            assert_eq!(heap.remove(handle), Some((handle * 37) % 64))
+        }
+        let mut copy = heap.clone();
+        heap.clear();
+        assert!(heap.is_empty() && !heap.contains(1));
+
+        let priorities: Vec<_> = std::iter::from_fn(|| copy.extract_min()).map(|(_, priority)| priority).collect();
+        assert_eq!(priorities.len(), 42);
+        assert!(priorities.windows(2).all(|pair| pair[0] < pair[1]))
+    }
+
+    #[test]
+    #[should_panic]
+    fn decrease_to_greater() {
+        let mut heap = IndexedHeap::new();
+        heap.insert(0, 1);
+        heap.decrease_key(0, 2)
+    }
+}
@@ -0,0 +1,10 @@
+//! Priority queues.
+mod compare;
+mod binary;
+mod indexed;
+mod pairing;
+
+pub use compare::{Compare, MaxFirst, MinFirst};
+pub use binary::{BinaryHeap, PeekMut};
+pub use indexed::IndexedHeap;
+pub use pairing::{Handle, Iter, PairingHeap};
@@ -0,0 +1,532 @@
+use crate::vec::Vector;
+
+use mem::ptr::NonNull;
+use std::cell::{Cell, RefCell};
+use std::fmt::{Debug, Formatter, Result};
+use std::iter::{FromIterator, FusedIterator};
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+type Link<T> = Option<NonNull<Node<T>>>;
+
+struct Node<T> {
+    item: T,
+    // leftmost child
+    child: Link<T>,
+    // right sibling
+    next: Link<T>,
+    // left sibling, or the parent for the leftmost child
+    prev: Link<T>,
+    slot: Rc<Slot<T>>
+}
+
+/// Identity of a heap, pointing to the one it was melded into.
+#[derive(Default)]
+struct Owner {
+    melded_into: RefCell<Option<Rc<Self>>>
+}
+
+/// Shared by a node and its handles, so they know when it is gone.
+struct Slot<T> {
+    node: Cell<Link<T>>,
+    owner: RefCell<Rc<Owner>>
+}
+
+/// Refers to an element of a [`PairingHeap`], even after melding.
+///
+/// The handle stays valid until its element leaves the heap.
+pub struct Handle<T> {
+    slot: Rc<Slot<T>>
+}
+
+impl<T> Clone for Handle<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self { slot: Rc::clone(&self.slot) }
+    }
+}
+
+/// Makes the root with the greater item the leftmost child of the other,
+/// returning the new root.
+///
+/// # Safety
+///
+/// Both must be valid and distinct roots, with no siblings.
+unsafe fn link<T: Ord>(a: NonNull<Node<T>>, b: NonNull<Node<T>>) -> NonNull<Node<T>> {
+    let (a, b) = (a.as_ptr(), b.as_ptr());
+    // SAFETY: guaranteed by the caller
+    unsafe {
+        let (parent, child) = if (*b).item < (*a).item { (b, a) } else { (a, b) };
+        let first = (*parent).child;
+        if let Some(first) = first {
+            (*first.as_ptr()).prev = NonNull::new(child)
+        }
+        (*child).next = first;
+        (*child).prev = NonNull::new(parent);
+        (*parent).child = NonNull::new(child);
+        NonNull::new_unchecked(parent)
+    }
+}
+
+/// Links a list of siblings into a single root, pairing them from left to
+/// right and then linking the pairs from right to left.
+///
+/// # Safety
+///
+/// `first` must start a valid list of siblings, which is not reachable
+/// from anywhere else afterwards.
+unsafe fn merge_pairs<T: Ord>(first: Link<T>) -> Link<T> {
+    // pairs are stacked through their 'next' links
+    let mut stacked: Link<T> = None;
+    let mut current = first;
+    while let Some(a) = current {
+        // SAFETY: every sibling is a valid node
+        unsafe {
+            let b = (*a.as_ptr()).next;
+            current = match b {
+                Some(b) => (*b.as_ptr()).next,
+                None => None
+            };
+            detach(a);
+            let pair = match b {
+                Some(b) => {
+                    detach(b);
+                    link(a, b)
+                },
+                None => a
+            };
+            (*pair.as_ptr()).next = stacked;
+            stacked = Some(pair)
+        }
+    }
+
+    let mut root = None;
+    while let Some(pair) = stacked {
+        // SAFETY: the pairs are valid roots, stacked only once
+        unsafe {
+            stacked = (*pair.as_ptr()).next;
+            (*pair.as_ptr()).next = None;
+            root = Some(match root {
+                Some(root) => link(root, pair),
+                None => pair
+            })
+        }
+    }
+    root
+}
+
+/// Forgets the siblings and parent of `node`, without updating them.
+///
+/// # Safety
+///
+/// `node` must be valid.
+#[inline]
+unsafe fn detach<T>(node: NonNull<Node<T>>) {
+    // SAFETY: guaranteed by the caller
+    unsafe {
+        (*node.as_ptr()).prev = None;
+        (*node.as_ptr()).next = None
+    }
+}
+
+/// Removes `node` and its subtree from its parent.
+///
+/// # Safety
+///
+/// `node` must be a valid node that is not a root.
+unsafe fn cut<T>(node: NonNull<Node<T>>) {
+    // SAFETY: guaranteed by the caller
+    unsafe {
+        let (prev, next) = ((*node.as_ptr()).prev, (*node.as_ptr()).next);
+        if let Some(prev) = prev {
+            if (*prev.as_ptr()).child == Some(node) {
+                (*prev.as_ptr()).child = next
+            } else {
+                (*prev.as_ptr()).next = next
+            }
+        }
+        if let Some(next) = next {
+            (*next.as_ptr()).prev = prev
+        }
+        detach(node)
+    }
+}
+
+/// Frees an unlinked node, invalidating its handles.
+///
+/// # Safety
+///
+/// `node` must be valid and must not be used after this.
+unsafe fn free<T>(node: NonNull<Node<T>>) -> T {
+    // SAFETY: every node is allocated as a box
+    let node = unsafe { Box::from_raw(node.as_ptr()) };
+    node.slot.node.set(None);
+    node.item
+}
+
+/// A min-heap of nodes in a multiway tree, where each root adopts the other
+/// as its leftmost child.
+///
+/// Pushing and melding take constant time and decreasing an element takes
+/// amortized sublogarithmic time, while popping takes amortized logarithmic
+/// time.
+pub struct PairingHeap<T: Ord> {
+    root: Link<T>,
+    length: usize,
+    owner: Rc<Owner>,
+    marker: PhantomData<Box<Node<T>>>
+}
+
+impl<T: Ord> PairingHeap<T> {
+    #[must_use]
+    #[inline]
+    pub fn new() -> Self {
+        Self { root: None, length: 0, owner: Rc::default(), marker: PhantomData }
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.length
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// The smallest element.
+    #[must_use]
+    #[inline]
+    pub fn peek(&self) -> Option<&T> {
+        // SAFETY: the root is valid and borrowed with the heap
+        self.root.map(|root| unsafe { &(*root.as_ptr()).item })
+    }
+
+    pub fn push(&mut self, item: T) -> Handle<T> {
+        let slot = Rc::new(Slot { node: Cell::new(None), owner: RefCell::new(Rc::clone(&self.owner)) });
+        let node = Box::new(Node { item, child: None, next: None, prev: None, slot: Rc::clone(&slot) });
+        // SAFETY: boxes are never null
+        let node = unsafe { NonNull::new_unchecked(Box::into_raw(node)) };
+        slot.node.set(Some(node));
+
+        self.root = Some(match self.root {
+            // SAFETY: both are valid roots
+            Some(root) => unsafe { link(root, node) },
+            None => node
+        });
+        self.length += 1;
+        Handle { slot }
+    }
+
+    /// Removes the smallest element.
+    pub fn pop(&mut self) -> Option<T> {
+        let root = self.root?;
+        // SAFETY: the root is valid and its children are only reachable
+        // from it
+        unsafe {
+            self.root = merge_pairs((*root.as_ptr()).child);
+            self.length -= 1;
+            Some(free(root))
+        }
+    }
+
+    /// Moves every element from `other` in constant time, keeping its
+    /// handles valid.
+    pub fn meld(&mut self, mut other: Self) {
+        *other.owner.melded_into.borrow_mut() = Some(Rc::clone(&self.owner));
+        self.length += std::mem::take(&mut other.length);
+        self.root = match (self.root, other.root.take()) {
+            // SAFETY: both are valid roots of different heaps
+            (Some(a), Some(b)) => Some(unsafe { link(a, b) }),
+            (a, b) => a.or(b)
+        }
+    }
+
+    /// The node of `handle`, if it is still in this heap.
+    fn locate(&self, handle: &Handle<T>) -> Option<NonNull<Node<T>>> {
+        let node = handle.slot.node.get()?;
+        let mut owner = handle.slot.owner.borrow_mut();
+        // follows the melds, remembering the last heap for next time
+        loop {
+            let next = owner.melded_into.borrow().clone();
+            match next {
+                Some(next) => *owner = next,
+                None => break
+            }
+        }
+        if Rc::ptr_eq(&owner, &self.owner) { Some(node) } else { None }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn contains(&self, handle: &Handle<T>) -> bool {
+        self.locate(handle).is_some()
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn get(&self, handle: &Handle<T>) -> Option<&T> {
+        let node = self.locate(handle)?;
+        // SAFETY: the node belongs to this heap, which is borrowed
+        Some(unsafe { &(*node.as_ptr()).item })
+    }
+
+    /// Replaces the element of `handle` with a smaller one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the element is not in this heap or if `item` is greater
+    /// than it.
+    pub fn decrease_key(&mut self, handle: &Handle<T>, item: T) {
+        let node = self.locate(handle).expect("handle should be in the heap");
+        // SAFETY: the node belongs to this heap, which is uniquely borrowed
+        unsafe {
+            assert!(item <= (*node.as_ptr()).item, "element should not increase");
+            (*node.as_ptr()).item = item;
+            if let Some(root) = self.root.filter(|&root| root != node) {
+                cut(node);
+                self.root = Some(link(root, node))
+            }
+        }
+    }
+
+    /// Removes the element of `handle`, if it is still in this heap.
+    pub fn delete(&mut self, handle: &Handle<T>) -> Option<T> {
+        let node = self.locate(handle)?;
+        let root = self.root?;
+        if root == node {
+            return self.pop()
+        }
+        // SAFETY: the node belongs to this heap and is not its root, and
+        // its children are only reachable from it
+        unsafe {
+            cut(node);
+            if let Some(children) = merge_pairs((*node.as_ptr()).child) {
+                self.root = Some(link(root, children))
+            }
+            self.length -= 1;
+            Some(free(node))
+        }
+    }
+
+    /// The elements in no particular order.
+    #[must_use]
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> {
+        let mut pending = Vector::new();
+        pending.extend(self.root);
+        Iter { pending, remaining: self.length, marker: PhantomData }
+    }
+
+    pub fn clear(&mut self) {
+        let mut pending = Vector::new();
+        pending.extend(self.root.take());
+        while let Some(node) = pending.pop() {
+            // SAFETY: each node is only reachable from its parent or left
+            // sibling, which were already freed
+            unsafe {
+                pending.extend((*node.as_ptr()).child);
+                pending.extend((*node.as_ptr()).next);
+                drop(free(node))
+            }
+        }
+        self.length = 0
+    }
+}
+
+pub struct Iter<'a, T> {
+    pending: Vector<NonNull<Node<T>>>,
+    remaining: usize,
+    marker: PhantomData<&'a Node<T>>
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a T> {
+        let node = self.pending.pop()?;
+        // SAFETY: the nodes are valid while the heap is borrowed
+        let node = unsafe { &*node.as_ptr() };
+        self.pending.extend(node.next);
+        self.pending.extend(node.child);
+        self.remaining -= 1;
+        Some(&node.item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+impl<T> Clone for Iter<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        let mut pending = Vector::with_capacity(self.pending.len());
+        pending.extend_from_slice(&self.pending);
+        Self { pending, remaining: self.remaining, marker: PhantomData }
+    }
+}
+
+impl<'a, T: Ord> IntoIterator for &'a PairingHeap<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<T: Ord> Drop for PairingHeap<T> {
+    #[inline]
+    fn drop(&mut self) {
+        self.clear()
+    }
+}
+
+impl<T: Ord> Default for PairingHeap<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord + Debug> Debug for PairingHeap<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Ord> FromIterator<T> for PairingHeap<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut heap = Self::new();
+        heap.extend(iter);
+        heap
+    }
+}
+
+impl<T: Ord> Extend<T> for PairingHeap<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push(item);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PairingHeap;
+    use crate::rand::XorShift;
+
+    #[test]
+    fn sorted_pops() {
+        let mut rng = XorShift::new(202);
+        let mut items: Vec<_> = (0..200).map(|_| rng.next_u64() % 1000).collect();
+        let mut heap: PairingHeap<_> = items.iter().copied().collect();
+        assert_eq!(heap.len(), 200);
+
+        items.sort_unstable();
+        assert_eq!(heap.peek(), items.first());
+        let popped: Vec<_> = std::iter::from_fn(|| heap.pop()).collect();
+        assert_eq!(popped, items);
+        assert!(heap.is_empty())
+    }
+
+    #[test]
+    fn iteration() {
+        let mut heap: PairingHeap<_> = (1..=6).rev().collect();
+        heap.pop();
+        let mut items: Vec<_> = heap.iter().copied().collect();
+        items.sort_unstable();
+        assert_eq!(items, vec![2, 3, 4, 5, 6]);
+        assert_eq!(heap.iter().len(), 5);
+        assert_eq!(format!("{:?}", heap), "[2, 3, 4, 5, 6]")
+    }
+
+    #[test]
+    fn handles() {
+        let mut heap = PairingHeap::new();
+        let handles: Vec<_> = (0..10).map(|i| heap.push(10 * i + 5)).collect();
+
+        heap.decrease_key(&handles[7], 1);
+        assert_eq!(heap.peek(), Some(&1));
+        assert_eq!(heap.get(&handles[3]), Some(&35));
+        assert_eq!(heap.delete(&handles[3]), Some(35));
+        assert_eq!(heap.delete(&handles[3]), None);
+        assert_eq!(heap.delete(&handles[7]), Some(1));
+        heap.decrease_key(&handles[9], 0);
+
+        assert_eq!(heap.pop(), Some(0));
+        assert!(!heap.contains(&handles[9]));
+        let popped: Vec<_> = std::iter::from_fn(|| heap.pop()).collect();
+        assert_eq!(popped, vec![5, 15, 25, 45, 55, 65, 85]);
+        assert!(handles.iter().all(|handle| heap.get(handle).is_none()))
+    }
+
+    #[test]
+    fn melding() {
+        let mut left = PairingHeap::new();
+        let mut right = PairingHeap::new();
+        let a = left.push("m");
+        let b = right.push("x");
+        right.push("c");
+        assert!(!left.contains(&b));
+
+        let mut third = PairingHeap::new();
+        let c = third.push("q");
+        right.meld(third);
+        left.meld(right);
+        assert_eq!(left.len(), 4);
+        assert!(left.contains(&a) && left.contains(&b) && left.contains(&c));
+
+        left.decrease_key(&b, "a");
+        assert_eq!(left.delete(&c), Some("q"));
+        let popped: Vec<_> = std::iter::from_fn(|| left.pop()).collect();
+        assert_eq!(popped, vec!["a", "c", "m"])
+    }
+
+    #[test]
+    fn foreign_handles() {
+        let mut heap = PairingHeap::new();
+        let mut other = PairingHeap::new();
+        let handle = other.push(1);
+        heap.push(2);
+        assert_eq!(heap.get(&handle), None);
+        assert_eq!(heap.delete(&handle.clone()), None);
+        assert_eq!(other.delete(&handle), Some(1))
+    }
+
+    #[test]
+    #[should_panic]
+    fn decrease_to_greater() {
+        let mut heap = PairingHeap::new();
+        let handle = heap.push(1);
+        heap.decrease_key(&handle, 2)
+    }
+
+    #[test]
+    fn drops() {
+        use std::rc::Rc;
+
+        let shared = Rc::new(());
+        let mut heap = PairingHeap::new();
+        for i in 0..20 {
+            heap.push((i % 7, Rc::clone(&shared)));
+        }
+        drop(heap.pop());
+        drop(heap.pop());
+        assert_eq!(Rc::strong_count(&shared), 19);
+        drop(heap);
+        assert_eq!(Rc::strong_count(&shared), 1)
+    }
+}
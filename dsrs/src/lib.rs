@@ -2,6 +2,8 @@
 #![feature(const_fn)]
 #![feature(const_mut_refs)]
 #![feature(const_option)]
+#![feature(unsize)]
+#![feature(allocator_api)]
 #![deny(unsafe_op_in_unsafe_fn)]
 #![warn(clippy::all, clippy::pedantic, clippy::nursery, clippy::cargo)]
 #![allow(clippy::cargo_common_metadata)]
@@ -9,3 +11,10 @@
 #![allow(clippy::wildcard_dependencies)]
 #![allow(clippy::module_name_repetitions)]
 pub mod lists;
+pub mod vec;
+pub mod queue;
+pub mod adapters;
+pub mod heap;
+pub mod tree;
+
+mod rand;
@@ -0,0 +1,64 @@
+use super::list::CircularList;
+
+use std::fmt::{Debug, Formatter, Result};
+use std::iter::FromIterator;
+
+impl<T: ?Sized> Drop for CircularList<T> {
+    #[inline]
+    fn drop(&mut self) {
+        self.clear()
+    }
+}
+
+impl<T: ?Sized> Default for CircularList<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: ?Sized + Debug> Debug for CircularList<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Clone> Clone for CircularList<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T> FromIterator<T> for CircularList<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T> Extend<T> for CircularList<T> {
+    /// Inserts each element at the end of the round.
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for elem in iter {
+            self.push_back(elem)
+        }
+    }
+}
+
+impl<'a, T: 'a + Copy> Extend<&'a T> for CircularList<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied())
+    }
+}
+
+// SAFETY: the ring of nodes is only reachable through the list, so
+// sending it moves every element along, which needs 'T: Send'
+unsafe impl<T: ?Sized + Send> Send for CircularList<T> {}
+// SAFETY: rotating needs '&mut CircularList', so shared access only reads
+// the ring and gives out '&T', fine to share when 'T: Sync'
+unsafe impl<T: ?Sized + Sync> Sync for CircularList<T> {}
@@ -0,0 +1,241 @@
+use super::list::CircularList;
+use super::node::{Link, Node};
+
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+
+pub struct Iter<'a, T: ?Sized> {
+    next: Link<T>,
+    remaining: usize,
+    marker: PhantomData<&'a Node<T>>
+}
+
+impl<T: ?Sized> Iter<'_, T> {
+    #[inline]
+    pub(super) const fn new(first: Link<T>, length: usize) -> Self {
+        Self { next: first, remaining: length, marker: PhantomData }
+    }
+}
+
+impl<'a, T: ?Sized> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a T> {
+        if self.remaining == 0 {
+            return None
+        }
+        self.next.map(|ptr| {
+            // SAFETY: 'ptr' is a valid node of a borrowed list
+            let node = unsafe { &*ptr.as_ptr() };
+            self.next = node.next;
+            self.remaining -= 1;
+            &node.data
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T: ?Sized> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T: ?Sized> FusedIterator for Iter<'_, T> {}
+
+impl<T: ?Sized> Clone for Iter<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.next, self.remaining)
+    }
+}
+
+pub struct IterMut<'a, T: ?Sized> {
+    next: Link<T>,
+    remaining: usize,
+    marker: PhantomData<&'a mut Node<T>>
+}
+
+impl<T: ?Sized> IterMut<'_, T> {
+    #[inline]
+    pub(super) const fn new(first: Link<T>, length: usize) -> Self {
+        Self { next: first, remaining: length, marker: PhantomData }
+    }
+}
+
+impl<'a, T: ?Sized> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a mut T> {
+        if self.remaining == 0 {
+            return None
+        }
+        self.next.map(|ptr| {
+            // SAFETY: 'ptr' is a valid node of a mutably borrowed list,
+            // and each node is yielded only once in a round
+            let node = unsafe { &mut *ptr.as_ptr() };
+            self.next = node.next;
+            self.remaining -= 1;
+            &mut node.data
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T: ?Sized> ExactSizeIterator for IterMut<'_, T> {}
+
+impl<T: ?Sized> FusedIterator for IterMut<'_, T> {}
+
+/// Endless iterator around a [`CircularList`].
+pub struct Cycle<'a, T: ?Sized> {
+    next: Link<T>,
+    marker: PhantomData<&'a Node<T>>
+}
+
+impl<T: ?Sized> Cycle<'_, T> {
+    #[inline]
+    pub(super) const fn new(first: Link<T>) -> Self {
+        Self { next: first, marker: PhantomData }
+    }
+}
+
+impl<'a, T: ?Sized> Iterator for Cycle<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a T> {
+        self.next.map(|ptr| {
+            // SAFETY: 'ptr' is a valid node of a borrowed list
+            let node = unsafe { &*ptr.as_ptr() };
+            self.next = node.next;
+            &node.data
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.next {
+            Some(_) => (usize::MAX, None),
+            None => (0, Some(0))
+        }
+    }
+}
+
+impl<T: ?Sized> FusedIterator for Cycle<'_, T> {}
+
+impl<T: ?Sized> Clone for Cycle<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.next)
+    }
+}
+
+pub struct IntoIter<T> {
+    list: CircularList<T>
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.list.remove_current()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len(), Some(self.list.len()))
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> FusedIterator for IntoIter<T> {}
+
+impl<T> IntoIterator for CircularList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    #[inline]
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter { list: self }
+    }
+}
+
+impl<'a, T: ?Sized> IntoIterator for &'a CircularList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T: ?Sized> IntoIterator for &'a mut CircularList<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+// SAFETY: 'Iter' goes once around a borrowed ring giving out '&T', so
+// it may move to another thread when 'T: Sync'
+unsafe impl<T: ?Sized + Sync> Send for Iter<'_, T> {}
+// SAFETY: a shared 'Iter' can only be cloned, which gives out the same
+// '&T', shareable when 'T: Sync'
+unsafe impl<T: ?Sized + Sync> Sync for Iter<'_, T> {}
+// SAFETY: 'IterMut' stops after one lap, so each '&mut T' is unique, and
+// moving them to another thread needs 'T: Send'
+unsafe impl<T: ?Sized + Send> Send for IterMut<'_, T> {}
+// SAFETY: a shared 'IterMut' can't advance, so like '&mut T' it only
+// needs 'T: Sync'
+unsafe impl<T: ?Sized + Sync> Sync for IterMut<'_, T> {}
+// SAFETY: 'Cycle' never ends, but it still only reads the borrowed ring
+// and gives out '&T', which needs 'T: Sync' on the other thread
+unsafe impl<T: ?Sized + Sync> Send for Cycle<'_, T> {}
+// SAFETY: a shared 'Cycle' can only be cloned, reading the same '&T'
+unsafe impl<T: ?Sized + Sync> Sync for Cycle<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::super::CircularList;
+
+    #[test]
+    fn endless_cycle() {
+        let mut list: CircularList<_> = (1..=3).collect();
+        list.rotate();
+
+        let taken: Vec<_> = list.cycle().take(7).copied().collect();
+        assert_eq!(taken, vec![2, 3, 1, 2, 3, 1, 2]);
+        assert_eq!(list.cycle().size_hint(), (usize::MAX, None));
+
+        let empty: CircularList<i32> = CircularList::new();
+        assert_eq!(empty.cycle().next(), None);
+        assert_eq!(empty.cycle().size_hint(), (0, Some(0)))
+    }
+
+    #[test]
+    fn single_round() {
+        let mut list: CircularList<_> = (1..=4).collect();
+        for elem in &mut list {
+            *elem *= 2
+        }
+        let mut iter = list.iter();
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.clone().sum::<i32>(), 18);
+
+        list.rotate_by(3);
+        assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![8, 2, 4, 6])
+    }
+}
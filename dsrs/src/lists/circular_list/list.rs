@@ -0,0 +1,286 @@
+use super::iter::{Cycle, Iter, IterMut};
+use super::node::{Link, Node};
+
+use mem::ptr::NonNull;
+use std::marker::PhantomData;
+
+/// A ring of nodes, where the last element links back to the first one.
+///
+/// The list only points to its last node, so the [`current`] element is
+/// always the one after it and a rotation just moves this pointer forward.
+///
+/// [`current`]: CircularList::current
+pub struct CircularList<T: ?Sized> {
+    pub(super) last: Link<T>,
+    pub(super) length: usize,
+    marker: PhantomData<Box<Node<T>>>
+}
+
+impl<T: ?Sized> CircularList<T> {
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self { last: None, length: 0, marker: PhantomData }
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.length
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.last.is_none()
+    }
+
+    /// Node with the current element, right after the last one.
+    #[inline]
+    fn first(&self) -> Link<T> {
+        // SAFETY: 'last' is a valid node owned by the list
+        self.last.and_then(|last| unsafe { (*last.as_ptr()).next })
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn current(&self) -> Option<&T> {
+        // SAFETY: 'first' is a valid node owned by the list
+        self.first().map(|first| unsafe { &(*first.as_ptr()).data })
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn current_mut(&mut self) -> Option<&mut T> {
+        // SAFETY: 'first' is a valid node owned by the list,
+        // which is mutably borrowed
+        self.first().map(|first| unsafe { &mut (*first.as_ptr()).data })
+    }
+
+    /// Moves to the next element, making the current one the last.
+    #[inline]
+    pub fn rotate(&mut self) {
+        self.last = self.first()
+    }
+
+    /// Moves `steps` elements forward, wrapping around the list.
+    #[inline]
+    pub fn rotate_by(&mut self, steps: usize) {
+        if self.is_empty() {
+            return
+        }
+        for _ in 0..steps % self.length {
+            self.rotate()
+        }
+    }
+
+    /// Iterates over a single round, starting at the current element.
+    #[must_use]
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter::new(self.first(), self.length)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut::new(self.first(), self.length)
+    }
+
+    /// Iterates forever around the list, starting at the current element.
+    ///
+    /// The iterator only ends if the list is empty.
+    #[must_use]
+    #[inline]
+    pub fn cycle(&self) -> Cycle<'_, T> {
+        Cycle::new(self.first())
+    }
+}
+
+impl<T: ?Sized> CircularList<T> {
+    /// Links the node right after the last one, returning its link.
+    #[inline]
+    fn link_node(&mut self, node: Box<Node<T>>) -> NonNull<Node<T>> {
+        debug_assert!(node.next.is_none());
+        let mut ptr = node.into_link();
+
+        if let Some(mut last) = self.last {
+            // SAFETY: both nodes are valid and owned by the list
+            unsafe {
+                ptr.as_mut().next = last.as_ref().next;
+                last.as_mut().next = Some(ptr)
+            }
+        } else {
+            // SAFETY: 'ptr' is a valid node, now owned by the list
+            unsafe { ptr.as_mut().next = Some(ptr) };
+            self.last = Some(ptr)
+        }
+        self.length += 1;
+        ptr
+    }
+
+    /// Inserts the node as the current element.
+    #[inline]
+    fn push_node_front(&mut self, node: Box<Node<T>>) {
+        self.link_node(node);
+    }
+
+    /// Inserts the node as the last element, just before the current one.
+    #[inline]
+    fn push_node_back(&mut self, node: Box<Node<T>>) {
+        self.last = Some(self.link_node(node))
+    }
+
+    #[must_use]
+    #[inline]
+    pub(super) fn remove_current_node(&mut self) -> Option<Box<Node<T>>> {
+        let mut last = self.last?;
+        let first = self.first()?;
+
+        if self.length == 1 {
+            self.last = None
+        } else {
+            // SAFETY: both nodes are valid and owned by the list
+            unsafe { last.as_mut().next = first.as_ref().next }
+        }
+        self.length -= 1;
+
+        // SAFETY: 'first' is owned by the list and is being unlinked
+        let mut node = unsafe { Node::from_link(first) };
+        node.next = None;
+        Some(node)
+    }
+
+    /// Inserts as the current element.
+    #[inline]
+    pub fn push_front_boxed(&mut self, data: Box<T>) {
+        self.push_node_front(Node::build(data))
+    }
+
+    /// Inserts as the last element, just before the current one.
+    #[inline]
+    pub fn push_back_boxed(&mut self, data: Box<T>) {
+        self.push_node_back(Node::build(data))
+    }
+
+    /// Removes the current element, moving to the next one.
+    #[must_use]
+    #[inline]
+    pub fn remove_current_boxed(&mut self) -> Option<Box<T>> {
+        self.remove_current_node().map(Node::split)
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        while self.remove_current_node().is_some() {}
+    }
+}
+
+impl<T> CircularList<T> {
+    /// Inserts as the current element.
+    #[inline]
+    pub fn push_front(&mut self, data: T) {
+        self.push_node_front(Box::new(Node { next: None, data }))
+    }
+
+    /// Inserts as the last element, just before the current one.
+    #[inline]
+    pub fn push_back(&mut self, data: T) {
+        self.push_node_back(Box::new(Node { next: None, data }))
+    }
+
+    /// Removes the current element, moving to the next one.
+    #[must_use]
+    #[inline]
+    pub fn remove_current(&mut self) -> Option<T> {
+        self.remove_current_node().map(|node| node.data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CircularList;
+
+    fn items<T: Copy>(list: &CircularList<T>) -> Vec<T> {
+        list.iter().copied().collect()
+    }
+
+    #[test]
+    fn rotation() {
+        let mut list = CircularList::new();
+        list.push_back(2);
+        list.push_back(3);
+        list.push_front(1);
+        assert_eq!(items(&list), vec![1, 2, 3]);
+        assert_eq!(list.current(), Some(&1));
+
+        list.rotate();
+        assert_eq!(items(&list), vec![2, 3, 1]);
+        list.rotate_by(5);
+        assert_eq!(list.current(), Some(&1));
+        list.push_back(4);
+        assert_eq!(items(&list), vec![1, 2, 3, 4]);
+
+        if let Some(current) = list.current_mut() {
+            *current *= 10
+        }
+        assert_eq!(list.remove_current(), Some(10));
+        assert_eq!(items(&list), vec![2, 3, 4]);
+        assert_eq!(list.len(), 3);
+
+        let mut empty: CircularList<i32> = CircularList::new();
+        empty.rotate_by(3);
+        assert_eq!(empty.remove_current(), None);
+        assert_eq!(empty.current(), None)
+    }
+
+    /// Round robin elimination, where every `step`-th participant leaves.
+    fn josephus(participants: u32, step: usize) -> Vec<u32> {
+        let mut round: CircularList<_> = (1..=participants).collect();
+        let mut order = Vec::new();
+        while !round.is_empty() {
+            round.rotate_by(step - 1);
+            order.extend(round.remove_current())
+        }
+        order
+    }
+
+    #[test]
+    fn elimination_order() {
+        assert_eq!(josephus(7, 3), vec![3, 6, 2, 7, 5, 1, 4]);
+        assert_eq!(josephus(5, 1), vec![1, 2, 3, 4, 5]);
+        assert_eq!(josephus(1, 4), vec![1])
+    }
+
+    #[test]
+    fn tournament() {
+        // (id, skill) pairs, like the participants from Lab04
+        let players = [(1, 7), (2, 3), (3, 9), (4, 1), (5, 4), (6, 8), (7, 2), (8, 6)];
+        let mut queue: CircularList<(u32, u32)> = players.iter().copied().collect();
+
+        // each match takes the first two in line and the winner goes back
+        // to the end, tired by the match
+        while queue.len() > 1 {
+            let first = queue.remove_current().unwrap();
+            let second = queue.remove_current().unwrap();
+            let (id, skill) = if first.1 >= second.1 { first } else { second };
+            queue.push_back((id, skill - 1))
+        }
+        assert_eq!(queue.current(), Some(&(3, 6)))
+    }
+
+    #[test]
+    fn unsized_nodes() {
+        let mut list: CircularList<str> = CircularList::new();
+        list.push_back_boxed("second".into());
+        list.push_front_boxed("first".into());
+        list.push_back_boxed("third".into());
+
+        list.iter_mut().for_each(str::make_ascii_uppercase);
+        list.rotate_by(2);
+        assert_eq!(list.iter().collect::<Vec<_>>(), vec!["THIRD", "FIRST", "SECOND"]);
+        assert_eq!(list.remove_current_boxed().as_deref(), Some("THIRD"));
+        assert_eq!(list.current(), Some("FIRST"));
+        assert_eq!(list.len(), 2)
+    }
+}
@@ -0,0 +1,7 @@
+mod node;
+mod list;
+mod iter;
+mod impls;
+
+pub use list::CircularList;
+pub use iter::{Cycle, IntoIter, Iter, IterMut};
@@ -0,0 +1,45 @@
+use mem::alloc::ReprC;
+use mem::ptr::NonNull;
+use mem::ReprC;
+
+pub(super) type Link<T> = Option<NonNull<Node<T>>>;
+
+#[repr(C)]
+#[derive(ReprC)]
+pub(super) struct Node<T: ?Sized> {
+    pub next: Link<T>,
+    pub data: T
+}
+
+impl<T: ?Sized> Node<T> {
+    #[inline]
+    pub fn build(data: Box<T>) -> Box<Self> {
+        ReprC::expand(data, (None,))
+    }
+
+    #[inline]
+    pub fn split(self: Box<Self>) -> Box<T> {
+        let (data, (next,)) = ReprC::split(self, true);
+        debug_assert!(next.is_none());
+        data
+    }
+
+    /// Leaks the node, so it can be linked.
+    #[inline]
+    pub fn into_link(self: Box<Self>) -> NonNull<Self> {
+        // SAFETY: boxes are never null
+        unsafe { NonNull::new_unchecked(Box::into_raw(self)) }
+    }
+
+    /// Recovers a node leaked with [`Node::into_link`].
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have been created by [`Node::into_link`] and must
+    /// not be used after this.
+    #[inline]
+    pub unsafe fn from_link(ptr: NonNull<Self>) -> Box<Self> {
+        // SAFETY: the caller guarantees this is a leaked box
+        unsafe { Box::from_raw(ptr.as_ptr()) }
+    }
+}
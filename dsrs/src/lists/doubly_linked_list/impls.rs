@@ -0,0 +1,56 @@
+use super::list::DoublyLinkedList;
+
+use std::fmt::{Debug, Formatter, Result};
+use std::iter::FromIterator;
+
+impl<T: ?Sized> Drop for DoublyLinkedList<T> {
+    #[inline]
+    fn drop(&mut self) {
+        self.clear()
+    }
+}
+
+impl<T: ?Sized> Default for DoublyLinkedList<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: ?Sized + Debug> Debug for DoublyLinkedList<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> FromIterator<T> for DoublyLinkedList<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T> Extend<T> for DoublyLinkedList<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for elem in iter {
+            self.push_tail(elem)
+        }
+    }
+}
+
+impl<'a, T: 'a + Copy> Extend<&'a T> for DoublyLinkedList<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied())
+    }
+}
+
+// SAFETY: the nodes are only reachable through the list, so sending it
+// moves every element to the other thread, which needs 'T: Send'
+unsafe impl<T: ?Sized + Send> Send for DoublyLinkedList<T> {}
+// SAFETY: '&DoublyLinkedList' only reads the links and gives out '&T',
+// which can be shared between threads when 'T: Sync'
+unsafe impl<T: ?Sized + Sync> Sync for DoublyLinkedList<T> {}
@@ -0,0 +1,200 @@
+use super::list::DoublyLinkedList;
+use super::node::{Link, Node};
+
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+
+pub struct Iter<'a, T: ?Sized> {
+    head: Link<T>,
+    tail: Link<T>,
+    remaining: usize,
+    marker: PhantomData<&'a Node<T>>
+}
+
+impl<T: ?Sized> Iter<'_, T> {
+    #[inline]
+    pub(super) const fn new(head: Link<T>, tail: Link<T>, length: usize) -> Self {
+        Self { head, tail, remaining: length, marker: PhantomData }
+    }
+}
+
+impl<'a, T: ?Sized> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a T> {
+        if self.remaining == 0 {
+            return None
+        }
+        self.head.map(|ptr| {
+            // SAFETY: 'ptr' is a valid node of a borrowed list
+            let node = unsafe { &*ptr.as_ptr() };
+            self.head = node.next;
+            self.remaining -= 1;
+            &node.data
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T: ?Sized> DoubleEndedIterator for Iter<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a T> {
+        if self.remaining == 0 {
+            return None
+        }
+        self.tail.map(|ptr| {
+            // SAFETY: 'ptr' is a valid node of a borrowed list
+            let node = unsafe { &*ptr.as_ptr() };
+            self.tail = node.prev;
+            self.remaining -= 1;
+            &node.data
+        })
+    }
+}
+
+impl<T: ?Sized> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T: ?Sized> FusedIterator for Iter<'_, T> {}
+
+impl<T: ?Sized> Clone for Iter<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.head, self.tail, self.remaining)
+    }
+}
+
+pub struct IterMut<'a, T: ?Sized> {
+    head: Link<T>,
+    tail: Link<T>,
+    remaining: usize,
+    marker: PhantomData<&'a mut Node<T>>
+}
+
+impl<T: ?Sized> IterMut<'_, T> {
+    #[inline]
+    pub(super) const fn new(head: Link<T>, tail: Link<T>, length: usize) -> Self {
+        Self { head, tail, remaining: length, marker: PhantomData }
+    }
+}
+
+impl<'a, T: ?Sized> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a mut T> {
+        if self.remaining == 0 {
+            return None
+        }
+        self.head.map(|ptr| {
+            // SAFETY: 'ptr' is a valid node of a mutably borrowed list,
+            // and each node is yielded only once
+            let node = unsafe { &mut *ptr.as_ptr() };
+            self.head = node.next;
+            self.remaining -= 1;
+            &mut node.data
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T: ?Sized> DoubleEndedIterator for IterMut<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a mut T> {
+        if self.remaining == 0 {
+            return None
+        }
+        self.tail.map(|ptr| {
+            // SAFETY: 'ptr' is a valid node of a mutably borrowed list,
+            // and each node is yielded only once
+            let node = unsafe { &mut *ptr.as_ptr() };
+            self.tail = node.prev;
+            self.remaining -= 1;
+            &mut node.data
+        })
+    }
+}
+
+impl<T: ?Sized> ExactSizeIterator for IterMut<'_, T> {}
+
+impl<T: ?Sized> FusedIterator for IterMut<'_, T> {}
+
+pub struct IntoIter<T> {
+    list: DoublyLinkedList<T>
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.list.pop_head()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len(), Some(self.list.len()))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        self.list.pop_tail()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> FusedIterator for IntoIter<T> {}
+
+impl<T> IntoIterator for DoublyLinkedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    #[inline]
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter { list: self }
+    }
+}
+
+impl<'a, T: ?Sized> IntoIterator for &'a DoublyLinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T: ?Sized> IntoIterator for &'a mut DoublyLinkedList<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+// SAFETY: 'Iter' walks from both ends of a borrowed list, giving out only
+// '&T', so it can be moved to a thread that could hold a '&T': 'T: Sync'
+unsafe impl<T: ?Sized + Sync> Send for Iter<'_, T> {}
+// SAFETY: a shared 'Iter' can only be cloned, which reads the same '&T'
+// from any thread when 'T: Sync'
+unsafe impl<T: ?Sized + Sync> Sync for Iter<'_, T> {}
+// SAFETY: 'IterMut' gives out each '&mut T' once, and moving unique access
+// to another thread is what 'T: Send' allows
+unsafe impl<T: ?Sized + Send> Send for IterMut<'_, T> {}
+// SAFETY: a shared 'IterMut' can't advance, so it gives no access to the
+// elements, and asking for 'T: Sync' matches '&mut T'
+unsafe impl<T: ?Sized + Sync> Sync for IterMut<'_, T> {}
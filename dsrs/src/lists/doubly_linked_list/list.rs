@@ -0,0 +1,311 @@
+use super::iter::{Iter, IterMut};
+use super::node::{Link, Node};
+
+use std::marker::PhantomData;
+
+pub struct DoublyLinkedList<T: ?Sized> {
+    pub(super) head: Link<T>,
+    pub(super) tail: Link<T>,
+    pub(super) length: usize,
+    marker: PhantomData<Box<Node<T>>>
+}
+
+impl<T: ?Sized> DoublyLinkedList<T> {
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self { head: None, tail: None, length: 0, marker: PhantomData }
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.length
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn head(&self) -> Option<&T> {
+        match self.head {
+            None => None,
+            Some(ref ptr) => {
+                // SAFETY: 'ptr' is a valid node owned by the list
+                let head = unsafe { ptr.as_ref() };
+                Some(&head.data)
+            }
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn head_mut(&mut self) -> Option<&mut T> {
+        match self.head {
+            None => None,
+            Some(ref mut ptr) => {
+                // SAFETY: 'ptr' is a valid node owned by the list,
+                // which is mutably borrowed
+                let head = unsafe { ptr.as_mut() };
+                Some(&mut head.data)
+            }
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn tail(&self) -> Option<&T> {
+        match self.tail {
+            None => None,
+            Some(ref ptr) => {
+                // SAFETY: 'ptr' is a valid node owned by the list
+                let tail = unsafe { ptr.as_ref() };
+                Some(&tail.data)
+            }
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn tail_mut(&mut self) -> Option<&mut T> {
+        match self.tail {
+            None => None,
+            Some(ref mut ptr) => {
+                // SAFETY: 'ptr' is a valid node owned by the list,
+                // which is mutably borrowed
+                let tail = unsafe { ptr.as_mut() };
+                Some(&mut tail.data)
+            }
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn iter(&self) -> Iter<'_, T> {
+        Iter::new(self.head, self.tail, self.length)
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut::new(self.head, self.tail, self.length)
+    }
+}
+
+impl<T: ?Sized> DoublyLinkedList<T> {
+    #[inline]
+    fn push_node_head(&mut self, node: Box<Node<T>>) {
+        debug_assert!(node.prev.is_none() && node.next.is_none());
+        let mut ptr = node.into_link();
+
+        match self.head.replace(ptr) {
+            // SAFETY: both nodes are valid and owned by the list
+            Some(mut head) => unsafe {
+                head.as_mut().prev = Some(ptr);
+                ptr.as_mut().next = Some(head)
+            },
+            None => self.tail = Some(ptr)
+        }
+        self.length += 1
+    }
+
+    #[inline]
+    fn push_node_tail(&mut self, node: Box<Node<T>>) {
+        debug_assert!(node.prev.is_none() && node.next.is_none());
+        let mut ptr = node.into_link();
+
+        match self.tail.replace(ptr) {
+            // SAFETY: both nodes are valid and owned by the list
+            Some(mut tail) => unsafe {
+                tail.as_mut().next = Some(ptr);
+                ptr.as_mut().prev = Some(tail)
+            },
+            None => self.head = Some(ptr)
+        }
+        self.length += 1
+    }
+
+    #[must_use]
+    #[inline]
+    pub(super) fn pop_node_head(&mut self) -> Option<Box<Node<T>>> {
+        self.head.map(|ptr| {
+            // SAFETY: 'ptr' is owned by the list and is being unlinked
+            let mut node = unsafe { Node::from_link(ptr) };
+
+            self.head = node.next.take();
+            match self.head {
+                // SAFETY: 'head' is a valid node owned by the list
+                Some(mut head) => unsafe { head.as_mut().prev = None },
+                None => self.tail = None
+            }
+            self.length -= 1;
+            node
+        })
+    }
+
+    #[must_use]
+    #[inline]
+    pub(super) fn pop_node_tail(&mut self) -> Option<Box<Node<T>>> {
+        self.tail.map(|ptr| {
+            // SAFETY: 'ptr' is owned by the list and is being unlinked
+            let mut node = unsafe { Node::from_link(ptr) };
+
+            self.tail = node.prev.take();
+            match self.tail {
+                // SAFETY: 'tail' is a valid node owned by the list
+                Some(mut tail) => unsafe { tail.as_mut().next = None },
+                None => self.head = None
+            }
+            self.length -= 1;
+            node
+        })
+    }
+
+    #[inline]
+    pub fn push_head_boxed(&mut self, data: Box<T>) {
+        self.push_node_head(Node::build(data))
+    }
+
+    #[inline]
+    pub fn push_tail_boxed(&mut self, data: Box<T>) {
+        self.push_node_tail(Node::build(data))
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn pop_head_boxed(&mut self) -> Option<Box<T>> {
+        self.pop_node_head().map(Node::split)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn pop_tail_boxed(&mut self) -> Option<Box<T>> {
+        self.pop_node_tail().map(Node::split)
+    }
+
+    /// Moves all elements from `other` to the end of the list, in
+    /// constant time.
+    #[inline]
+    pub fn append(&mut self, other: &mut Self) {
+        let mut tail = match self.tail {
+            Some(tail) => tail,
+            None => return std::mem::swap(self, other)
+        };
+
+        if let Some(mut head) = other.head.take() {
+            // SAFETY: both are valid nodes, one from each list
+            unsafe {
+                tail.as_mut().next = Some(head);
+                head.as_mut().prev = Some(tail)
+            }
+            self.tail = other.tail.take();
+            self.length += std::mem::replace(&mut other.length, 0)
+        }
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        while self.pop_node_head().is_some() {}
+    }
+}
+
+impl<T> DoublyLinkedList<T> {
+    #[inline]
+    pub fn push_head(&mut self, data: T) {
+        self.push_node_head(Box::new(Node { prev: None, next: None, data }))
+    }
+
+    #[inline]
+    pub fn push_tail(&mut self, data: T) {
+        self.push_node_tail(Box::new(Node { prev: None, next: None, data }))
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn pop_head(&mut self) -> Option<T> {
+        self.pop_node_head().map(|node| node.data)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn pop_tail(&mut self) -> Option<T> {
+        self.pop_node_tail().map(|node| node.data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DoublyLinkedList;
+
+    #[test]
+    fn both_ends() {
+        let mut list = DoublyLinkedList::new();
+        list.push_tail(2);
+        list.push_head(1);
+        list.push_tail(3);
+        assert_eq!(list.len(), 3);
+        assert_eq!((list.head(), list.tail()), (Some(&1), Some(&3)));
+
+        assert_eq!(list.pop_tail(), Some(3));
+        assert_eq!(list.pop_head(), Some(1));
+        assert_eq!(list.pop_tail(), Some(2));
+        assert_eq!(list.pop_tail(), None);
+        assert_eq!((list.head(), list.tail()), (None, None));
+        assert!(list.is_empty())
+    }
+
+    #[test]
+    fn double_ended_iteration() {
+        let mut list: DoublyLinkedList<_> = (1..=6).collect();
+        let mut iter = list.iter_mut();
+        *iter.next().unwrap() *= 10;
+        *iter.next_back().unwrap() *= 10;
+        assert_eq!(iter.len(), 4);
+
+        let back: Vec<_> = list.iter().rev().copied().collect();
+        assert_eq!(back, vec![60, 5, 4, 3, 2, 10]);
+
+        let mut iter = list.into_iter();
+        assert_eq!(iter.next_back(), Some(60));
+        assert_eq!(iter.next(), Some(10));
+        assert_eq!(iter.collect::<Vec<_>>(), vec![2, 3, 4, 5])
+    }
+
+    #[test]
+    fn constant_append() {
+        let mut list: DoublyLinkedList<_> = (0..3).collect();
+        let mut other: DoublyLinkedList<_> = (3..5).collect();
+
+        list.append(&mut other);
+        assert!(other.is_empty());
+        assert_eq!(list.len(), 5);
+        assert_eq!(format!("{:?}", list), "[0, 1, 2, 3, 4]");
+
+        other.append(&mut list);
+        assert!(list.is_empty());
+        assert_eq!(other.tail(), Some(&4));
+        assert_eq!(other.pop_tail(), Some(4));
+        assert_eq!(other.len(), 4)
+    }
+
+    #[test]
+    fn unsized_nodes() {
+        let mut list: DoublyLinkedList<str> = DoublyLinkedList::new();
+        list.push_tail_boxed("middle".into());
+        list.push_head_boxed("first".into());
+        list.push_tail_boxed("last".into());
+
+        if let Some(text) = list.tail_mut() {
+            text.make_ascii_uppercase()
+        }
+        assert_eq!(format!("{:?}", list), r#"["first", "middle", "LAST"]"#);
+
+        assert_eq!(list.pop_tail_boxed().as_deref(), Some("LAST"));
+        assert_eq!(list.pop_head_boxed().as_deref(), Some("first"));
+        assert_eq!(list.iter().rev().collect::<Vec<_>>(), vec!["middle"])
+    }
+}
@@ -0,0 +1,7 @@
+mod node;
+mod list;
+mod iter;
+mod impls;
+
+pub use list::DoublyLinkedList;
+pub use iter::{IntoIter, Iter, IterMut};
@@ -0,0 +1,368 @@
+use super::list::LinkedList;
+use super::node::{Next, Node, NodeBox};
+
+use mem::ptr::NonNull;
+use std::alloc::{AllocRef, Global};
+
+/// A cursor over a [`LinkedList`] that can edit the list in place.
+///
+/// The cursor sits either on an element or on the "ghost" position after
+/// the tail, from where [`move_next`](CursorMut::move_next) wraps around
+/// to the head. Since the list is singly linked, the cursor can only move
+/// forward and every insertion happens *after* the current element.
+pub struct CursorMut<'a, T: ?Sized, A: AllocRef = Global> {
+    list: &'a mut LinkedList<T, A>,
+    // node linking to the current one, `None` when the current node is
+    // the head, and the tail while on the ghost position
+    prev: Option<NonNull<Node<T>>>,
+    index: usize
+}
+
+impl<'a, T: ?Sized, A: AllocRef> CursorMut<'a, T, A> {
+    #[inline]
+    pub(super) const fn new(list: &'a mut LinkedList<T, A>) -> Self {
+        Self { list, prev: None, index: 0 }
+    }
+
+    #[inline]
+    const fn is_ghost(&self) -> bool {
+        self.index == self.list.length
+    }
+
+    /// Link holding the current node.
+    #[inline]
+    const fn link(&self) -> &Next<T> {
+        match self.prev {
+            // SAFETY: 'prev' is a node owned by the list, which is
+            // borrowed by the cursor
+            Some(ref prev) => unsafe { &prev.as_ref().next },
+            None => &self.list.head
+        }
+    }
+
+    /// Mutable link holding the current node.
+    #[inline]
+    const fn link_mut(&mut self) -> &mut Next<T> {
+        match self.prev {
+            // SAFETY: 'prev' is a node owned by the list, which is
+            // mutably borrowed by the cursor
+            Some(ref mut prev) => unsafe { &mut prev.as_mut().next },
+            None => &mut self.list.head
+        }
+    }
+
+    /// Index of the current element, `None` on the ghost position.
+    #[must_use]
+    #[inline]
+    pub const fn index(&self) -> Option<usize> {
+        if self.is_ghost() {
+            None
+        } else {
+            Some(self.index)
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn current(&mut self) -> Option<&mut T> {
+        self.link_mut().as_deref_mut().map(|node| &mut node.data)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn peek_next(&mut self) -> Option<&mut T> {
+        let next = if self.is_ghost() {
+            &mut self.list.head
+        } else {
+            &mut self.link_mut().as_mut()?.next
+        };
+        next.as_deref_mut().map(|node| &mut node.data)
+    }
+
+    /// Moves to the next element, or to the head when on the ghost
+    /// position.
+    #[inline]
+    pub fn move_next(&mut self) {
+        if self.is_ghost() {
+            self.prev = None;
+            self.index = 0
+        } else {
+            self.prev = self.link().as_deref().map(Node::as_ptr);
+            self.index += 1
+        }
+    }
+
+    #[inline]
+    pub(super) fn insert_node_after(&mut self, mut node: NodeBox<T>) {
+        if self.is_ghost() {
+            // SAFETY: new nodes have no next
+            unsafe { self.list.push_node_head(node) };
+            // ghost is always after the tail
+            self.prev = self.list.tail;
+            self.index += 1;
+            return
+        }
+
+        let current = match self.link_mut() {
+            Some(current) => current,
+            None => return
+        };
+        let next = current.next.take();
+        let last = next.is_none();
+        if let Some(next) = next {
+            // SAFETY: 'node' has just been built
+            unsafe { node.push_next_unchecked(next) }
+        }
+        let ptr = node.as_ptr();
+        // SAFETY: 'next' was just taken
+        unsafe { current.push_next_unchecked(node) };
+
+        if last {
+            self.list.tail = Some(ptr)
+        }
+        self.list.length += 1
+    }
+
+    #[must_use]
+    #[inline]
+    pub(super) fn remove_current_node(&mut self) -> Option<NodeBox<T>> {
+        let link = self.link_mut();
+        let mut node = link.take()?;
+        *link = node.next.take();
+
+        if link.is_none() {
+            // removed the last node
+            self.list.tail = self.prev
+        }
+        self.list.length -= 1;
+        Some(node)
+    }
+
+    /// Removes and drops the current element, moving to the next one.
+    #[inline]
+    pub(super) fn discard_current(&mut self) {
+        if let Some(node) = self.remove_current_node() {
+            // SAFETY: nodes are allocated by the list allocator
+            unsafe { node.free_in(&mut self.list.alloc) };
+        }
+    }
+}
+
+/// Like [`LinkedList::append`], only relinks nodes between lists using
+/// `Global`.
+impl<T: ?Sized> CursorMut<'_, T> {
+    /// Moves all elements from `other` after the current element, or to
+    /// the head when on the ghost position.
+    #[inline]
+    pub fn splice_after(&mut self, mut other: LinkedList<T>) {
+        let (head, mut tail) = match (other.head.take(), other.tail.take()) {
+            (Some(head), Some(tail)) => (head, tail),
+            (_, _) => return
+        };
+        let length = std::mem::replace(&mut other.length, 0);
+
+        let ghost = self.is_ghost();
+        let next = if ghost {
+            self.list.head.replace(head)
+        } else {
+            match self.link_mut() {
+                Some(current) => current.next.replace(head),
+                None => return
+            }
+        };
+        match next {
+            // SAFETY: 'tail' is the last node of 'other', so its next is None
+            Some(next) => unsafe { tail.as_mut().push_next_unchecked(next) },
+            None => self.list.tail = Some(tail)
+        }
+        self.list.length += length;
+
+        if ghost {
+            // ghost is always after the tail
+            self.prev = self.list.tail;
+            self.index += length
+        }
+    }
+}
+
+impl<T: ?Sized, A: AllocRef + Clone> CursorMut<'_, T, A> {
+    /// Splits the list after the current element, returning everything
+    /// after it. On the ghost position, the whole list is returned.
+    #[must_use]
+    #[inline]
+    pub fn split_after(&mut self) -> LinkedList<T, A> {
+        if self.is_ghost() {
+            self.prev = None;
+            self.index = 0;
+            let empty = LinkedList::new_in(self.list.alloc.clone());
+            return std::mem::replace(self.list, empty)
+        }
+
+        let length = self.list.length - self.index - 1;
+        let current = match self.link_mut() {
+            Some(current) => current,
+            None => return LinkedList::new_in(self.list.alloc.clone())
+        };
+        let head = current.next.take();
+        let tail = current.as_ptr();
+
+        let mut rest = LinkedList::new_in(self.list.alloc.clone());
+        if head.is_some() {
+            rest.head = head;
+            rest.tail = self.list.tail.replace(tail);
+            rest.length = length;
+            self.list.length -= length
+        }
+        rest
+    }
+}
+
+impl<T: ?Sized> CursorMut<'_, T> {
+    /// Inserts after the current element, or at the head when on the ghost
+    /// position.
+    #[inline]
+    pub fn insert_after_boxed(&mut self, data: Box<T>) {
+        self.insert_node_after(Node::build(data, None))
+    }
+
+    /// Removes the current element and moves to the next one.
+    #[must_use]
+    #[inline]
+    pub fn remove_current_boxed(&mut self) -> Option<Box<T>> {
+        // SAFETY: nodes are allocated by 'Global'
+        self.remove_current_node().map(|node| unsafe { node.split() }.0)
+    }
+}
+
+impl<T, A: AllocRef> CursorMut<'_, T, A> {
+    /// Inserts after the current element, or at the head when on the ghost
+    /// position.
+    #[inline]
+    pub fn insert_after(&mut self, data: T) {
+        let node = Node::new_in(data, None, &mut self.list.alloc);
+        self.insert_node_after(node)
+    }
+
+    /// Removes the current element and moves to the next one.
+    #[must_use]
+    #[inline]
+    pub fn remove_current(&mut self) -> Option<T> {
+        let node = self.remove_current_node()?;
+        // SAFETY: nodes are allocated by the list allocator
+        Some(unsafe { node.into_data_in(&mut self.list.alloc) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::LinkedList;
+
+    /// Sorted insertion, like `insere_dado` from Lab03.
+    fn insert_sorted(list: &mut LinkedList<i32>, value: i32) {
+        let mut cursor = list.cursor_front_mut();
+        if cursor.current().map_or(true, |head| *head >= value) {
+            // insert at the head, from the ghost position
+            while cursor.index().is_some() {
+                cursor.move_next()
+            }
+            return cursor.insert_after(value)
+        }
+        while cursor.peek_next().map_or(false, |next| *next < value) {
+            cursor.move_next()
+        }
+        cursor.insert_after(value)
+    }
+
+    /// Removal of the first equal element, like `remove_dado` from Lab03.
+    fn remove_value(list: &mut LinkedList<i32>, value: i32) -> bool {
+        let mut cursor = list.cursor_front_mut();
+        while let Some(current) = cursor.current() {
+            if *current == value {
+                return cursor.remove_current().is_some()
+            }
+            cursor.move_next()
+        }
+        false
+    }
+
+    fn items<T: Copy>(list: &LinkedList<T>) -> Vec<T> {
+        list.iter().copied().collect()
+    }
+
+    #[test]
+    fn sorted_list() {
+        let mut list = LinkedList::new();
+        for &value in &[5, 1, 4, 9, 2, 7, 0] {
+            insert_sorted(&mut list, value)
+        }
+        assert_eq!(items(&list), vec![0, 1, 2, 4, 5, 7, 9]);
+        assert_eq!(list.tail(), Some(&9));
+
+        assert!(remove_value(&mut list, 9));
+        assert!(remove_value(&mut list, 0));
+        assert!(remove_value(&mut list, 4));
+        assert!(!remove_value(&mut list, 3));
+        assert_eq!(items(&list), vec![1, 2, 5, 7]);
+        assert_eq!(list.tail(), Some(&7));
+        assert_eq!(list.len(), 4);
+
+        list.push_tail(8);
+        assert_eq!(items(&list), vec![1, 2, 5, 7, 8])
+    }
+
+    #[test]
+    fn split_and_splice() {
+        let mut list: LinkedList<_> = (0..6).collect();
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&mut 2));
+
+        let mut rest = cursor.split_after();
+        assert_eq!(cursor.index(), Some(2));
+        assert_eq!(cursor.peek_next(), None);
+        assert_eq!(items(&rest), vec![3, 4, 5]);
+        assert_eq!(rest.tail(), Some(&5));
+
+        let mut other = rest.cursor_front_mut();
+        other.move_next();
+        other.splice_after((10..12).collect());
+        assert_eq!(other.remove_current(), Some(4));
+        assert_eq!(other.current(), Some(&mut 10));
+
+        cursor.move_next();
+        assert_eq!(cursor.index(), None);
+        cursor.splice_after(rest);
+        assert_eq!(cursor.index(), None);
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&mut 3));
+
+        assert_eq!(items(&list), vec![3, 10, 11, 5, 0, 1, 2]);
+        assert_eq!(list.tail(), Some(&2));
+        assert_eq!(list.len(), 7);
+
+        let whole = list.cursor_front_mut().split_after();
+        assert_eq!(items(&whole), vec![10, 11, 5, 0, 1, 2]);
+        assert_eq!(items(&list), vec![3]);
+        assert_eq!(list.tail(), Some(&3))
+    }
+
+    #[test]
+    fn unsized_nodes() {
+        let mut list: LinkedList<[u8]> = LinkedList::new();
+        let mut cursor = list.cursor_front_mut();
+        cursor.insert_after_boxed(vec![1, 2].into());
+        cursor.move_next();
+        cursor.insert_after_boxed(vec![3].into());
+        cursor.insert_after_boxed(vec![4, 5, 6].into());
+
+        assert_eq!(cursor.remove_current_boxed().as_deref(), Some(&[1, 2][..]));
+        assert_eq!(cursor.current(), Some(&mut [4, 5, 6][..]));
+        cursor.move_next();
+        assert_eq!(cursor.remove_current_boxed().as_deref(), Some(&[3][..]));
+        assert_eq!(cursor.index(), None);
+
+        assert_eq!(list.len(), 1);
+        assert_eq!(list.tail(), Some(&[4, 5, 6][..]))
+    }
+}
@@ -1,9 +1,203 @@
-use super::node::Node;
+use super::list::LinkedList;
 
-use std::fmt::{Result, Display, Formatter};
+use std::alloc::AllocRef;
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display, Formatter, Result};
+use std::hash::{Hash, Hasher};
+use std::iter::FromIterator;
 
-impl<T: ?Sized> Display for Node<T> {
+impl<T: ?Sized + Debug, A: AllocRef> Debug for LinkedList<T, A> {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(f, "node")
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: ?Sized + Display, A: AllocRef> Display for LinkedList<T, A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "[")?;
+        for (i, elem) in self.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?
+            }
+            // keep the formatting options for each element
+            Display::fmt(elem, f)?
+        }
+        write!(f, "]")
+    }
+}
+
+impl<T: ?Sized, A: AllocRef> Drop for LinkedList<T, A> {
+    #[inline]
+    fn drop(&mut self) {
+        self.clear()
+    }
+}
+
+impl<T: ?Sized, A: AllocRef + Default> Default for LinkedList<T, A> {
+    #[inline]
+    fn default() -> Self {
+        Self::new_in(A::default())
+    }
+}
+
+impl<T: Clone, A: AllocRef + Clone> Clone for LinkedList<T, A> {
+    #[inline]
+    fn clone(&self) -> Self {
+        let mut list = Self::new_in(self.alloc.clone());
+        list.extend(self.iter().cloned());
+        list
+    }
+}
+
+impl<U, T, A, B> PartialEq<LinkedList<U, B>> for LinkedList<T, A>
+where
+    U: ?Sized,
+    T: ?Sized + PartialEq<U>,
+    A: AllocRef,
+    B: AllocRef
+{
+    #[inline]
+    fn eq(&self, other: &LinkedList<U, B>) -> bool {
+        if self.length != other.length {
+            return false
+        }
+
+        match (&self.head, &other.head) {
+            (Some(this), Some(other)) => **this == **other,
+            (None, None) => true,
+            (_, _) => false
+        }
+    }
+}
+
+impl<T: ?Sized + Eq, A: AllocRef> Eq for LinkedList<T, A> {}
+
+impl<T: ?Sized + PartialOrd, A: AllocRef> PartialOrd for LinkedList<T, A> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T: ?Sized + Ord, A: AllocRef> Ord for LinkedList<T, A> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<T: ?Sized + Hash, A: AllocRef> Hash for LinkedList<T, A> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.length.hash(state);
+        if let Some(head) = &self.head {
+            (**head).hash(state)
+        }
+    }
+}
+
+impl<T, A: AllocRef + Default> FromIterator<T> for LinkedList<T, A> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::default();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T, A: AllocRef> Extend<T> for LinkedList<T, A> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for elem in iter {
+            self.push_tail(elem)
+        }
+    }
+}
+
+impl<'a, T: 'a + Copy, A: AllocRef> Extend<&'a T> for LinkedList<T, A> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LinkedList;
+
+    use std::collections::HashMap;
+
+    #[test]
+    fn comparisons() {
+        let list: LinkedList<_> = vec![1, 2, 3].into_iter().collect();
+        let copy = list.clone();
+        assert_eq!(list, copy);
+        assert_eq!(LinkedList::<i32>::default(), LinkedList::new());
+
+        let mut longer = copy;
+        longer.push_tail(0);
+        assert_ne!(list, longer);
+        assert!(list < longer);
+
+        longer.push_head(0);
+        assert!(longer < list);
+        assert_eq!(longer.cmp(&longer.clone()), std::cmp::Ordering::Equal);
+
+        let texts: LinkedList<_> = vec!["a", "b"].into_iter().collect();
+        let strings: LinkedList<_> = vec![String::from("a"), String::from("b")].into_iter().collect();
+        assert_eq!(strings, texts)
+    }
+
+    #[test]
+    fn unsized_comparisons() {
+        let mut list: LinkedList<str> = LinkedList::new();
+        let mut other: LinkedList<str> = LinkedList::new();
+        for text in &["one", "two"] {
+            list.push_tail_boxed((*text).into());
+            other.push_tail_boxed((*text).into())
+        }
+        assert_eq!(list, other);
+
+        other.push_tail_boxed("three".into());
+        assert!(list < other)
+    }
+
+    #[test]
+    fn map_keys() {
+        let mut counts = HashMap::new();
+        for _ in 0..3 {
+            let key: LinkedList<_> = (0..4).collect();
+            *counts.entry(key).or_insert(0) += 1
+        }
+        *counts.entry(LinkedList::new()).or_insert(0) += 1;
+
+        assert_eq!(counts.len(), 2);
+        assert_eq!(counts[&(0..4).collect()], 3)
+    }
+
+    #[test]
+    fn long_list_clone() {
+        let list: LinkedList<_> = (0..1_000_000).collect();
+        let copy = list.clone();
+        assert_eq!(list, copy)
+    }
+
+    #[test]
+    fn formatting() {
+        let mut list: LinkedList<f64> = LinkedList::new();
+        assert_eq!(format!("{:?}", list), "[]");
+        assert_eq!(format!("{}", list), "[]");
+
+        list.extend(&[1.5, 2.0, 3.25]);
+        assert_eq!(format!("{:?}", list), "[1.5, 2.0, 3.25]");
+        assert_eq!(format!("{}", list), "[1.5, 2, 3.25]");
+        assert_eq!(format!("{:.1}", list), "[1.5, 2.0, 3.2]");
+
+        let mut texts: LinkedList<str> = LinkedList::new();
+        texts.push_tail_boxed("one".into());
+        texts.push_tail_boxed("two".into());
+        assert_eq!(format!("{:?}", texts), r#"["one", "two"]"#);
+        assert_eq!(format!("{}", texts), "[one, two]");
+        assert_eq!(format!("{:#?}", texts), "[\n    \"one\",\n    \"two\",\n]")
     }
 }
@@ -0,0 +1,212 @@
+use super::list::LinkedList;
+use super::node::Node;
+
+use std::alloc::{AllocRef, Global};
+use std::iter::FusedIterator;
+
+pub struct Iter<'a, T: ?Sized> {
+    next: Option<&'a Node<T>>,
+    remaining: usize
+}
+
+impl<'a, T: ?Sized> Iter<'a, T> {
+    #[inline]
+    pub(super) const fn new(head: Option<&'a Node<T>>, length: usize) -> Self {
+        Self { next: head, remaining: length }
+    }
+}
+
+impl<'a, T: ?Sized> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a T> {
+        self.next.map(|node| {
+            self.next = node.next.as_deref();
+            self.remaining -= 1;
+            &node.data
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T: ?Sized> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T: ?Sized> FusedIterator for Iter<'_, T> {}
+
+impl<T: ?Sized> Clone for Iter<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self { next: self.next, remaining: self.remaining }
+    }
+}
+
+pub struct IterMut<'a, T: ?Sized> {
+    next: Option<&'a mut Node<T>>,
+    remaining: usize
+}
+
+impl<'a, T: ?Sized> IterMut<'a, T> {
+    #[inline]
+    pub(super) const fn new(head: Option<&'a mut Node<T>>, length: usize) -> Self {
+        Self { next: head, remaining: length }
+    }
+}
+
+impl<'a, T: ?Sized> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a mut T> {
+        self.next.take().map(|node| {
+            self.next = node.next.as_deref_mut();
+            self.remaining -= 1;
+            &mut node.data
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T: ?Sized> ExactSizeIterator for IterMut<'_, T> {}
+
+impl<T: ?Sized> FusedIterator for IterMut<'_, T> {}
+
+/// Owning iterator, which pops each element from the list.
+///
+/// Since the list has no back links, each call to
+/// [`next_back`](DoubleEndedIterator::next_back) walks from the head to
+/// the tail, so consuming the whole iterator in reverse, as with
+/// [`rev`](Iterator::rev), takes quadratic time.
+pub struct IntoIter<T, A: AllocRef = Global> {
+    list: LinkedList<T, A>
+}
+
+impl<T, A: AllocRef> Iterator for IntoIter<T, A> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.list.pop()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len(), Some(self.list.len()))
+    }
+}
+
+impl<T, A: AllocRef> DoubleEndedIterator for IntoIter<T, A> {
+    /// Takes linear time, as the list has no back links.
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        self.list.pop_tail()
+    }
+}
+
+impl<T, A: AllocRef> ExactSizeIterator for IntoIter<T, A> {}
+
+impl<T, A: AllocRef> FusedIterator for IntoIter<T, A> {}
+
+impl<T, A: AllocRef> IntoIterator for LinkedList<T, A> {
+    type Item = T;
+    type IntoIter = IntoIter<T, A>;
+
+    #[inline]
+    fn into_iter(self) -> IntoIter<T, A> {
+        IntoIter { list: self }
+    }
+}
+
+impl<'a, T: ?Sized, A: AllocRef> IntoIterator for &'a LinkedList<T, A> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T: ?Sized, A: AllocRef> IntoIterator for &'a mut LinkedList<T, A> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::LinkedList;
+
+    #[test]
+    fn borrowing_iteration() {
+        let mut list = LinkedList::new();
+        for i in 1..=4 {
+            list.push_tail(i)
+        }
+
+        let mut iter = list.iter();
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.clone().sum::<i32>(), 9);
+
+        for elem in list.iter_mut() {
+            *elem *= 10
+        }
+        let items: Vec<_> = list.iter().copied().collect();
+        assert_eq!(items, vec![10, 20, 30, 40]);
+        assert_eq!(list.len(), 4)
+    }
+
+    #[test]
+    fn unsized_iteration() {
+        let mut list: LinkedList<str> = LinkedList::new();
+        list.push_tail_boxed("second".into());
+        list.push_head_boxed("first".into());
+
+        for text in list.iter_mut() {
+            text.make_ascii_uppercase()
+        }
+        let items: Vec<_> = list.iter().collect();
+        assert_eq!(items, vec!["FIRST", "SECOND"]);
+        assert_eq!(list.iter().len(), 2)
+    }
+
+    #[test]
+    fn owning_iteration() {
+        let mut list: LinkedList<_> = (1..=5).collect();
+        list.extend(vec![6, 7]);
+        list.extend(&[8]);
+        assert_eq!(list.len(), 8);
+
+        for elem in &mut list {
+            *elem += 1
+        }
+        let mut sum = 0;
+        for elem in &list {
+            sum += elem
+        }
+        assert_eq!(sum, 44);
+
+        let mut iter = list.into_iter();
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next_back(), Some(9));
+        assert_eq!(iter.next_back(), Some(8));
+        assert_eq!(iter.len(), 5);
+
+        let rest: Vec<_> = iter.rev().collect();
+        assert_eq!(rest, vec![7, 6, 5, 4, 3])
+    }
+}
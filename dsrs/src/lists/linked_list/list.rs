@@ -1,19 +1,37 @@
-use super::node::Node;
+use super::cursor::CursorMut;
+use super::iter::{Iter, IterMut};
+use super::node::{Node, NodeBox};
 
 use mem::ptr::NonNull;
+use std::alloc::{AllocRef, Global};
 
-#[derive(Debug)]
-pub struct LinkedList<T: ?Sized> {
-    pub(super) head: Option<Box<Node<T>>>,
+pub struct LinkedList<T: ?Sized, A: AllocRef = Global> {
+    pub(super) head: Option<NodeBox<T>>,
     pub(super) tail: Option<NonNull<Node<T>>>,
-    pub(super) length: usize
+    pub(super) length: usize,
+    pub(super) alloc: A
 }
 
 impl<T: ?Sized> LinkedList<T> {
     #[must_use]
     #[inline]
     pub const fn new() -> Self {
-        Self { head: None, tail: None, length: 0 }
+        Self::new_in(Global)
+    }
+}
+
+impl<T: ?Sized, A: AllocRef> LinkedList<T, A> {
+    /// Empty list whose nodes are allocated by `alloc`.
+    #[must_use]
+    #[inline]
+    pub const fn new_in(alloc: A) -> Self {
+        Self { head: None, tail: None, length: 0, alloc }
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn allocator(&self) -> &A {
+        &self.alloc
     }
 
     #[must_use]
@@ -28,6 +46,18 @@ impl<T: ?Sized> LinkedList<T> {
         self.head.is_none()
     }
 
+    #[must_use]
+    #[inline]
+    pub fn head(&self) -> Option<&T> {
+        self.head.as_ref().map(|node| &node.data)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn head_mut(&mut self) -> Option<&mut T> {
+        self.head.as_mut().map(|node| &mut node.data)
+    }
+
     #[must_use]
     #[inline]
     pub const fn tail(&self) -> Option<&T> {
@@ -54,11 +84,41 @@ impl<T: ?Sized> LinkedList<T> {
             }
         }
     }
+
+    #[must_use]
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter::new(self.head.as_deref(), self.length)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut::new(self.head.as_deref_mut(), self.length)
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn cursor_front_mut(&mut self) -> CursorMut<'_, T, A> {
+        CursorMut::new(self)
+    }
+
+    /// Removes every element, dropping the nodes iteratively.
+    #[inline]
+    pub fn clear(&mut self) {
+        let mut next = self.head.take();
+        while let Some(node) = next {
+            // SAFETY: every node was allocated by the list allocator
+            next = unsafe { node.free_in(&mut self.alloc) }
+        }
+        self.tail = None;
+        self.length = 0
+    }
 }
 
-impl<T: ?Sized> LinkedList<T> {
+impl<T: ?Sized, A: AllocRef> LinkedList<T, A> {
     #[inline]
-    unsafe fn push_node_head(&mut self, mut node: Box<Node<T>>) {
+    pub(super) unsafe fn push_node_head(&mut self, mut node: NodeBox<T>) {
         if let Some(next) = self.head.take() {
             // SAFETY: caller must ensure that 'node.next' is
             // currently 'None'
@@ -73,7 +133,7 @@ impl<T: ?Sized> LinkedList<T> {
     }
 
     #[inline]
-    unsafe fn push_node_tail(&mut self, node: Box<Node<T>>) {
+    pub(super) unsafe fn push_node_tail(&mut self, node: NodeBox<T>) {
         if let Some(mut ptr) = self.tail.replace(node.as_ptr()) {
             // SAFETY: 'tail' is a valid mutable reference
             let tail = unsafe { ptr.as_mut() };
@@ -87,6 +147,87 @@ impl<T: ?Sized> LinkedList<T> {
         self.length += 1
     }
 
+    #[must_use]
+    #[inline]
+    pub(super) fn pop_node(&mut self) -> Option<NodeBox<T>> {
+        self.head.take().map(|mut node| {
+            self.head = node.next.take();
+            if self.head.is_none() {
+                self.tail = None
+            }
+            self.length -= 1;
+            node
+        })
+    }
+
+    #[must_use]
+    #[inline]
+    pub(super) fn pop_tail_node(&mut self) -> Option<NodeBox<T>> {
+        if self.length < 2 {
+            return self.pop_node()
+        }
+
+        // walk to the second to last node
+        let mut prev = self.head.as_deref_mut()?;
+        for _ in 2..self.length {
+            prev = prev.next.as_deref_mut()?
+        }
+        let node = prev.next.take();
+        debug_assert!(node.as_ref().map_or(false, |node| node.next.is_none()));
+
+        self.tail = Some(prev.as_ptr());
+        self.length -= 1;
+        node
+    }
+
+    #[must_use]
+    #[inline]
+    fn remove_node(&mut self, index: usize) -> Option<NodeBox<T>> {
+        if index >= self.length {
+            return None
+        }
+
+        let mut cursor = self.cursor_front_mut();
+        for _ in 0..index {
+            cursor.move_next()
+        }
+        cursor.remove_current_node()
+    }
+
+    /// Builds the node only after checking the index, so nothing leaks
+    /// on panic.
+    #[inline]
+    unsafe fn insert_node<F>(&mut self, index: usize, build: F)
+    where
+        F: FnOnce(&mut A) -> NodeBox<T>
+    {
+        assert!(
+            index <= self.length,
+            "insertion index (is {}) should be <= len (is {})",
+            index,
+            self.length
+        );
+        let node = build(&mut self.alloc);
+
+        if index == 0 {
+            // SAFETY: caller must ensure that 'node.next' is None
+            return unsafe { self.push_node_head(node) }
+        } else if index == self.length {
+            // SAFETY: caller must ensure that 'node.next' is None
+            return unsafe { self.push_node_tail(node) }
+        }
+
+        let mut cursor = self.cursor_front_mut();
+        for _ in 1..index {
+            cursor.move_next()
+        }
+        cursor.insert_node_after(node)
+    }
+}
+
+/// Boxes always live in the global allocator, so the boxed elements can
+/// only be moved in and out of lists using it.
+impl<T: ?Sized> LinkedList<T> {
     #[inline]
     pub fn push_head_boxed(&mut self, data: Box<T>) {
         // SAFETY: next is None
@@ -101,35 +242,121 @@ impl<T: ?Sized> LinkedList<T> {
 
     #[must_use]
     #[inline]
-    fn pop_node(&mut self) -> Option<Box<Node<T>>> {
-        self.head.take().map(|mut node| {
-            self.head = node.next.take();
-            if self.head.is_none() {
-                self.tail = None
+    pub fn pop_boxed(&mut self) -> Option<Box<T>> {
+        // SAFETY: nodes are allocated by 'Global'
+        self.pop_node().map(|node| unsafe { node.split() }.0)
+    }
+
+    /// Takes linear time, as the list has no back links.
+    #[must_use]
+    #[inline]
+    pub fn pop_tail_boxed(&mut self) -> Option<Box<T>> {
+        // SAFETY: nodes are allocated by 'Global'
+        self.pop_tail_node().map(|node| unsafe { node.split() }.0)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn remove_boxed(&mut self, index: usize) -> Option<Box<T>> {
+        // SAFETY: nodes are allocated by 'Global'
+        self.remove_node(index).map(|node| unsafe { node.split() }.0)
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    #[inline]
+    pub fn insert_boxed(&mut self, index: usize, data: Box<T>) {
+        // SAFETY: next is None
+        unsafe { self.insert_node(index, |_| Node::build(data, None)) }
+    }
+}
+
+/// Two lists may use different instances of the same allocator type, so
+/// nodes can only be relinked from one list to another when both use
+/// `Global`, where any node can be freed by any list.
+impl<T: ?Sized> LinkedList<T> {
+    /// Moves all elements from `other` to the end of the list, in
+    /// constant time.
+    #[inline]
+    pub fn append(&mut self, other: &mut Self) {
+        let mut tail = match self.tail {
+            Some(tail) => tail,
+            None => return std::mem::swap(self, other)
+        };
+
+        if let Some(head) = other.head.take() {
+            // SAFETY: 'tail' is the last node, so its next is None
+            unsafe { tail.as_mut().push_next_unchecked(head) }
+            self.tail = other.tail.take();
+            self.length += std::mem::replace(&mut other.length, 0)
+        }
+    }
+}
+
+impl<T: ?Sized, A: AllocRef> LinkedList<T, A> {
+    #[inline]
+    pub fn reverse(&mut self) {
+        let mut current = self.head.take();
+        self.tail = current.as_deref().map(Node::as_ptr);
+
+        let mut reversed = None;
+        while let Some(mut node) = current {
+            current = std::mem::replace(&mut node.next, reversed);
+            reversed = Some(node)
+        }
+        self.head = reversed
+    }
+
+    /// Keeps only the elements where `keep` returns `true`, in order.
+    #[inline]
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut keep: F) {
+        let mut cursor = self.cursor_front_mut();
+        while let Some(elem) = cursor.current() {
+            if keep(elem) {
+                cursor.move_next()
+            } else {
+                cursor.discard_current()
             }
-            self.length -= 1;
-            node
-        })
+        }
     }
+}
 
+impl<T: ?Sized, A: AllocRef + Clone> LinkedList<T, A> {
+    /// Splits the list at the given index, returning everything from
+    /// `at` onwards.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > len`.
     #[must_use]
     #[inline]
-    pub fn pop_boxed(&mut self) -> Option<Box<T>> {
-        self.pop_node().map(|x| x.split().0)
+    pub fn split_off(&mut self, at: usize) -> Self {
+        assert!(at <= self.length, "cannot split off at a nonexistent index");
+        if at == 0 {
+            let empty = Self::new_in(self.alloc.clone());
+            return std::mem::replace(self, empty)
+        }
+
+        let mut cursor = self.cursor_front_mut();
+        for _ in 1..at {
+            cursor.move_next()
+        }
+        cursor.split_after()
     }
 }
 
-impl<T> LinkedList<T> {
+impl<T, A: AllocRef> LinkedList<T, A> {
     #[inline]
     pub fn push_head(&mut self, data: T) {
-        let node = Box::new(Node { next: None, data });
+        let node = Node::new_in(data, None, &mut self.alloc);
         // SAFETY: next is None
         unsafe { self.push_node_head(node) }
     }
 
     #[inline]
     pub fn push_tail(&mut self, data: T) {
-        let node = Box::new(Node { next: None, data });
+        let node = Node::new_in(data, None, &mut self.alloc);
         // SAFETY: next is None
         unsafe { self.push_node_tail(node) }
     }
@@ -137,15 +364,277 @@ impl<T> LinkedList<T> {
     #[must_use]
     #[inline]
     pub fn pop(&mut self) -> Option<T> {
-        self.pop_node().map(|node| node.data)
+        let node = self.pop_node()?;
+        // SAFETY: nodes are allocated by the list allocator
+        Some(unsafe { node.into_data_in(&mut self.alloc) })
     }
-}
 
-impl<T> Iterator for LinkedList<T> {
-    type Item = T;
+    /// Takes linear time, as the list has no back links.
+    #[must_use]
+    #[inline]
+    pub fn pop_tail(&mut self) -> Option<T> {
+        let node = self.pop_tail_node()?;
+        // SAFETY: nodes are allocated by the list allocator
+        Some(unsafe { node.into_data_in(&mut self.alloc) })
+    }
 
+    #[must_use]
     #[inline]
-    fn next(&mut self) -> Option<T> {
-        self.pop()
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        let node = self.remove_node(index)?;
+        // SAFETY: nodes are allocated by the list allocator
+        Some(unsafe { node.into_data_in(&mut self.alloc) })
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    #[inline]
+    pub fn insert(&mut self, index: usize, data: T) {
+        // SAFETY: next is None
+        unsafe { self.insert_node(index, |alloc| Node::new_in(data, None, alloc)) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LinkedList;
+
+    use std::alloc::{AllocErr, AllocRef, Global, Layout};
+    use std::cell::Cell;
+    use std::ptr::NonNull;
+    use std::rc::Rc;
+
+    fn items<T: Copy, A: AllocRef>(list: &LinkedList<T, A>) -> Vec<T> {
+        list.iter().copied().collect()
+    }
+
+    /// Global allocator that counts every allocation and the live ones.
+    #[derive(Debug, Clone, Default)]
+    struct Counting {
+        total: Rc<Cell<usize>>,
+        live: Rc<Cell<usize>>
+    }
+
+    unsafe impl AllocRef for Counting {
+        fn alloc(&mut self, layout: Layout) -> Result<NonNull<[u8]>, AllocErr> {
+            self.total.set(self.total.get() + 1);
+            self.live.set(self.live.get() + 1);
+            Global.alloc(layout)
+        }
+
+        unsafe fn dealloc(&mut self, ptr: NonNull<u8>, layout: Layout) {
+            self.live.set(self.live.get() - 1);
+            // SAFETY: every block comes from 'Global'
+            unsafe { Global.dealloc(ptr, layout) }
+        }
+    }
+
+    #[test]
+    fn tail_removal() {
+        let mut list: LinkedList<_> = (1..=3).collect();
+        assert_eq!(list.pop_tail(), Some(3));
+        assert_eq!(list.tail(), Some(&2));
+
+        list.push_tail(4);
+        assert_eq!(items(&list), vec![1, 2, 4]);
+        assert_eq!(list.pop_tail(), Some(4));
+        assert_eq!(list.pop_tail(), Some(2));
+        assert_eq!(list.pop_tail(), Some(1));
+        assert_eq!(list.pop_tail(), None);
+        assert_eq!(list.tail(), None);
+        assert!(list.is_empty());
+
+        list.push_tail(5);
+        assert_eq!(list.tail(), Some(&5));
+        assert_eq!(list.len(), 1)
+    }
+
+    #[test]
+    fn indexed_edits() {
+        let mut list = LinkedList::new();
+        list.insert(0, 'b');
+        list.insert(0, 'a');
+        list.insert(2, 'd');
+        list.insert(2, 'c');
+        assert_eq!(items(&list), vec!['a', 'b', 'c', 'd']);
+        assert_eq!(list.tail(), Some(&'d'));
+
+        assert_eq!(list.remove(4), None);
+        assert_eq!(list.remove(3), Some('d'));
+        assert_eq!(list.tail(), Some(&'c'));
+        assert_eq!(list.remove(1), Some('b'));
+        assert_eq!(list.remove(0), Some('a'));
+        assert_eq!(items(&list), vec!['c']);
+        assert_eq!(list.len(), 1)
+    }
+
+    #[test]
+    #[should_panic]
+    fn insert_out_of_bounds() {
+        let mut list: LinkedList<_> = (0..2).collect();
+        list.insert(3, 3)
+    }
+
+    #[test]
+    fn unsized_edits() {
+        let mut list: LinkedList<str> = LinkedList::new();
+        list.insert_boxed(0, "three".into());
+        list.insert_boxed(0, "one".into());
+        list.insert_boxed(1, "two".into());
+
+        assert_eq!(list.remove_boxed(1).as_deref(), Some("two"));
+        assert_eq!(list.pop_tail_boxed().as_deref(), Some("three"));
+        assert_eq!(list.tail(), Some("one"));
+        assert_eq!(list.len(), 1)
+    }
+
+    #[test]
+    fn long_list_drop() {
+        let mut list = LinkedList::new();
+        for i in 0..1_000_000 {
+            list.push_head(i)
+        }
+        assert_eq!(list.len(), 1_000_000);
+        drop(list);
+
+        let mut list: LinkedList<str> = LinkedList::new();
+        for _ in 0..200_000 {
+            list.push_tail_boxed("unsized".into())
+        }
+        drop(list)
+    }
+
+    #[test]
+    fn relinking() {
+        let mut list: LinkedList<_> = (0..3).collect();
+        let mut other: LinkedList<_> = (3..6).collect();
+        list.append(&mut other);
+        assert!(other.is_empty() && other.tail().is_none());
+        assert_eq!(items(&list), vec![0, 1, 2, 3, 4, 5]);
+        assert_eq!(list.tail(), Some(&5));
+
+        other.append(&mut list);
+        assert_eq!((list.len(), other.len()), (0, 6));
+
+        let mut rest = other.split_off(4);
+        assert_eq!(items(&other), vec![0, 1, 2, 3]);
+        assert_eq!(items(&rest), vec![4, 5]);
+        assert_eq!((other.tail(), rest.tail()), (Some(&3), Some(&5)));
+        assert!(rest.split_off(2).is_empty());
+        let all = rest.split_off(0);
+        assert!(rest.is_empty() && rest.tail().is_none());
+        assert_eq!(all.len(), 2);
+
+        other.reverse();
+        assert_eq!(items(&other), vec![3, 2, 1, 0]);
+        assert_eq!(other.tail(), Some(&0));
+
+        other.retain(|x| x % 2 == 1);
+        assert_eq!(items(&other), vec![3, 1]);
+        assert_eq!(other.tail(), Some(&1));
+        other.retain(|&x| x > 1);
+        assert_eq!(other.tail(), Some(&3));
+        assert_eq!(other.len(), 1)
+    }
+
+    #[test]
+    fn unsized_relinking() {
+        let mut list: LinkedList<[u32]> = LinkedList::new();
+        list.push_tail_boxed(vec![1].into());
+        list.push_tail_boxed(vec![2, 2].into());
+        let mut other: LinkedList<[u32]> = LinkedList::new();
+        other.push_head_boxed(vec![3, 3, 3].into());
+        other.push_head_boxed(vec![].into());
+
+        let address = other.tail().map(<[u32]>::as_ptr);
+        list.append(&mut other);
+        list.reverse();
+        assert_eq!(list.head().map(<[u32]>::as_ptr), address);
+        assert_eq!(list.tail(), Some(&[1][..]));
+
+        list.retain(|slice| !slice.is_empty());
+        let rest = list.split_off(1);
+        assert_eq!(list.iter().collect::<Vec<_>>(), vec![&[3, 3, 3][..]]);
+        assert_eq!(rest.iter().collect::<Vec<_>>(), vec![&[2, 2][..], &[1][..]])
+    }
+
+    #[test]
+    fn counted_allocations() {
+        let alloc = Counting::default();
+        let (total, live) = (alloc.total.clone(), alloc.live.clone());
+
+        let mut list = LinkedList::new_in(alloc);
+        list.extend(0..5);
+        list.insert(2, 10);
+        assert_eq!((total.get(), live.get()), (6, 6));
+
+        assert_eq!(list.pop(), Some(0));
+        assert_eq!(list.remove(1), Some(10));
+        assert_eq!(list.pop_tail(), Some(4));
+        assert_eq!((total.get(), live.get()), (6, 3));
+
+        let mut rest = list.split_off(1);
+        rest.push_head(7);
+        while let Some(elem) = rest.pop() {
+            list.push_tail(elem)
+        }
+        assert_eq!(items(&list), vec![1, 7, 2, 3]);
+        assert_eq!((total.get(), live.get()), (10, 4));
+
+        let copy = list.clone();
+        list.retain(|&x| x < 3);
+        assert_eq!(items(&list), vec![1, 2]);
+        assert_eq!(items(&copy), vec![1, 7, 2, 3]);
+        assert_eq!((total.get(), live.get()), (14, 6));
+        drop((list, copy, rest));
+        assert_eq!((total.get(), live.get()), (14, 0))
+    }
+
+    #[test]
+    fn separate_allocators() {
+        let (first, second) = (Counting::default(), Counting::default());
+        let (first_live, second_live) = (first.live.clone(), second.live.clone());
+
+        let mut list = LinkedList::new_in(first);
+        list.extend(0..3);
+        let mut other = LinkedList::new_in(second);
+        other.extend(3..5);
+        assert_eq!((first_live.get(), second_live.get()), (3, 2));
+
+        // elements move between the lists, but each node is freed by the
+        // allocator that built it
+        list.extend(other.split_off(0));
+        other.push_head(list.pop().unwrap_or_default());
+        assert_eq!(items(&list), vec![1, 2, 3, 4]);
+        assert_eq!(items(&other), vec![0]);
+        assert_eq!((first_live.get(), second_live.get()), (4, 1));
+
+        drop((list, other));
+        assert_eq!((first_live.get(), second_live.get()), (0, 0))
+    }
+
+    #[test]
+    fn counted_unsized_allocations() {
+        let alloc = Counting::default();
+        let (total, live) = (alloc.total.clone(), alloc.live.clone());
+
+        let mut texts: LinkedList<str, _> = LinkedList::new_in(alloc.clone());
+        texts.push_tail_str("one");
+        texts.push_head_str("two");
+        let mut slices: LinkedList<[u8], _> = LinkedList::new_in(alloc.clone());
+        slices.push_tail_slice(&[1, 2, 3]);
+        let mut objects: LinkedList<dyn std::fmt::Debug, _> = LinkedList::new_in(alloc);
+        objects.push_tail_dyn(1.5);
+        objects.push_tail_dyn("two");
+        assert_eq!((total.get(), live.get()), (5, 5));
+
+        texts.sort();
+        texts.dedup_by_key(|text| text.len());
+        assert_eq!(texts.iter().collect::<Vec<_>>(), vec!["one"]);
+        assert_eq!(live.get(), 4);
+
+        drop((texts, slices, objects));
+        assert_eq!((total.get(), live.get()), (5, 0))
     }
 }
@@ -1,5 +1,11 @@
 mod node;
 mod list;
+mod iter;
+mod cursor;
+mod sort;
+mod unsize;
 mod impls;
 
 pub use list::LinkedList;
+pub use iter::{IntoIter, Iter, IterMut};
+pub use cursor::CursorMut;
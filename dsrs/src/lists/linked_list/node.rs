@@ -1,28 +1,125 @@
-use mem::alloc::ReprC;
+use mem::alloc::{FieldTuple, Layout, ReprC};
 use mem::ptr::NonNull;
 use mem::ReprC;
 
+use std::alloc::{handle_alloc_error, AllocRef, Global};
+use std::fmt::{Debug, Formatter, Result};
 use std::hash::{Hash, Hasher};
+use std::marker::Unsize;
+use std::ops::{Deref, DerefMut};
+use std::ptr::{drop_in_place, slice_from_raw_parts_mut};
 
-type Next<T> = Option<Box<Node<T>>>;
+pub(super) type Next<T> = Option<NodeBox<T>>;
 
 #[repr(C)]
-#[derive(Debug, Clone, ReprC)]
+#[derive(Debug, ReprC)]
 pub(super) struct Node<T: ?Sized> {
     pub next: Next<T>,
     pub data: T
 }
 
-impl<T: ?Sized> Node<T> {
+/// Owning pointer to a node, allocated by the list allocator.
+///
+/// Works just like a `Box`, but it is never dropped by itself, so the list
+/// must free each node with the allocator that built it.
+#[repr(transparent)]
+pub(super) struct NodeBox<T: ?Sized>(NonNull<Node<T>>);
+
+impl<T: ?Sized> NodeBox<T> {
+    /// # Safety
+    ///
+    /// `ptr` must be an initialized node, not owned by anything else.
+    #[inline]
+    const unsafe fn from_raw(ptr: NonNull<Node<T>>) -> Self {
+        Self(ptr)
+    }
+
+    /// Drops the node and frees its memory, returning the next link.
+    ///
+    /// # Safety
+    ///
+    /// The node must have been allocated by `alloc`.
+    #[inline]
+    pub unsafe fn free_in<A: AllocRef>(mut self, alloc: &mut A) -> Next<T> {
+        let next = self.next.take();
+        let layout = Layout::for_value(&*self);
+        // SAFETY: the node is initialized and owned by 'self'
+        unsafe { drop_in_place(self.0.as_ptr()) };
+        // SAFETY: the caller guarantees that 'alloc' built the node
+        unsafe { alloc.dealloc(self.0.cast().inner(), layout.inner()) };
+        next
+    }
+
+    /// Splits the node into a box with its data, reusing its memory.
+    ///
+    /// # Safety
+    ///
+    /// The node must have been allocated by `Global`.
     #[inline]
-    pub fn build(data: Box<T>, next: Next<T>) -> Box<Self> {
-        ReprC::expand(data, (next,))
+    pub unsafe fn split(self) -> (Box<T>, Next<T>) {
+        // SAFETY: the caller guarantees that 'Global' built the node
+        let (data, (next,)) = unsafe { ReprC::split_in(self.0, true, &mut Global) };
+        // SAFETY: the shrunk data fits a box from the global allocator
+        (unsafe { Box::from_raw(data.as_ptr()) }, next)
     }
+}
+
+impl<T> NodeBox<T> {
+    /// Moves the data out of the node and frees its memory.
+    ///
+    /// # Safety
+    ///
+    /// The node must have been allocated by `alloc`.
+    #[inline]
+    pub unsafe fn into_data_in<A: AllocRef>(self, alloc: &mut A) -> T {
+        // SAFETY: the node is initialized and owned by 'self'
+        let Node { next, data } = unsafe { self.0.as_ptr().read() };
+        debug_assert!(next.is_none());
+        // SAFETY: the caller guarantees that 'alloc' built the node
+        unsafe { alloc.dealloc(self.0.cast().inner(), Layout::new::<Node<T>>().inner()) };
+        data
+    }
+}
+
+impl<T: ?Sized> Deref for NodeBox<T> {
+    type Target = Node<T>;
 
     #[inline]
-    pub fn split(self: Box<Self>) -> (Box<T>, Next<T>) {
-        let (data, (next,)) = ReprC::split(self, true);
-        (data, next)
+    fn deref(&self) -> &Node<T> {
+        // SAFETY: the node is owned by 'self'
+        unsafe { &*self.0.as_ptr() }
+    }
+}
+
+impl<T: ?Sized> DerefMut for NodeBox<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Node<T> {
+        // SAFETY: the node is uniquely owned by 'self'
+        unsafe { &mut *self.0.as_ptr() }
+    }
+}
+
+impl<T: ?Sized + Debug> Debug for NodeBox<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Debug::fmt(&**self, f)
+    }
+}
+
+// SAFETY: 'NodeBox' owns its node, like a 'Box'
+unsafe impl<T: ?Sized + Send> Send for NodeBox<T> {}
+// SAFETY: 'NodeBox' owns its node, like a 'Box'
+unsafe impl<T: ?Sized + Sync> Sync for NodeBox<T> {}
+
+impl<T: ?Sized> Node<T> {
+    /// Builds the node in the global allocator, reusing the memory of `data`.
+    #[inline]
+    pub fn build(data: Box<T>, next: Next<T>) -> NodeBox<T> {
+        // SAFETY: a box is never null
+        let data = unsafe { NonNull::new_unchecked(Box::into_raw(data)) };
+        // SAFETY: a box is always allocated by 'Global'
+        let node = unsafe { ReprC::expand_in(data, (next,), &mut Global) };
+        // SAFETY: the node was just built
+        unsafe { NodeBox::from_raw(node) }
     }
 
     #[inline]
@@ -31,12 +128,121 @@ impl<T: ?Sized> Node<T> {
     }
 
     #[inline]
-    pub unsafe fn push_next_unchecked(&mut self, next: Box<Self>) {
+    pub unsafe fn push_next_unchecked(&mut self, next: NodeBox<T>) {
         debug_assert!(self.next.is_none());
         // SAFETY: write is valid, since we take it by reference
         // but caller must guarantee that drop is not necessary
         unsafe { std::ptr::write(&mut self.next, Some(next)) }
     }
+
+    /// Allocates a node whose data has the given layout, returning the
+    /// block, its layout and the data offset.
+    fn allocate<A: AllocRef>(data: Layout, alloc: &mut A) -> (*mut u8, Layout, usize) {
+        let start = <<Self as ReprC>::Fields as FieldTuple>::START_LAYOUT;
+        let (layout, offset) = start.extend(data).expect("capacity overflow");
+        let layout = layout.pad_to_align();
+
+        // the layout has at least the size of 'next'
+        match alloc.alloc(layout.inner()) {
+            Ok(ptr) => (ptr.cast().as_ptr(), layout, offset),
+            Err(_) => handle_alloc_error(layout.inner())
+        }
+    }
+
+    /// Builds the node for a sized value directly with the layout for the
+    /// unsized `T`.
+    ///
+    /// `Node<U>` can't be coerced, since `next` also depends on `T`.
+    pub fn unsize_in<U: Unsize<T>, A: AllocRef>(data: U, next: Next<T>, alloc: &mut A) -> NodeBox<T> {
+        let (ptr, layout, offset) = Self::allocate(Layout::new::<U>(), alloc);
+        // SAFETY: 'offset' is inside the allocated block and aligned for 'U'
+        unsafe { (ptr.add(offset) as *mut U).write(data) };
+        // SAFETY: 'ptr' is a repr(C) node with its data initialized
+        unsafe { <Self as ReprC>::Fields::write_start(ptr, (next,)) };
+
+        // metadata comes from the unsized data pointer
+        let node = ptr as *mut U as *mut T as *mut Self;
+        // SAFETY: the node is fully initialized and 'ptr' is not null
+        let node = unsafe { NodeBox::from_raw(NonNull::new_unchecked(node)) };
+        debug_assert_eq!(Layout::for_value(&*node), layout);
+        node
+    }
+}
+
+impl<T> Node<T> {
+    #[inline]
+    pub fn new_in<A: AllocRef>(data: T, next: Next<T>, alloc: &mut A) -> NodeBox<T> {
+        let (ptr, layout, _) = Self::allocate(Layout::new::<T>(), alloc);
+        debug_assert_eq!(layout, Layout::new::<Self>());
+
+        let ptr = ptr as *mut Self;
+        // SAFETY: 'ptr' was allocated for a node
+        unsafe { ptr.write(Self { next, data }) };
+        // SAFETY: the node is initialized and 'ptr' is not null
+        unsafe { NodeBox::from_raw(NonNull::new_unchecked(ptr)) }
+    }
+}
+
+impl<T: Clone> Node<[T]> {
+    /// Builds the node directly at its final allocation, cloning each
+    /// element of `slice`.
+    pub fn from_slice_in<A: AllocRef>(slice: &[T], next: Next<[T]>, alloc: &mut A) -> NodeBox<[T]> {
+        /// Frees the allocation and drops the cloned elements
+        /// if a `clone` panics.
+        struct Guard<'a, T, A: AllocRef> {
+            alloc: &'a mut A,
+            ptr: *mut u8,
+            layout: Layout,
+            data: *mut T,
+            written: usize
+        }
+
+        impl<T, A: AllocRef> Drop for Guard<'_, T, A> {
+            fn drop(&mut self) {
+                // SAFETY: only the first 'written' elements were initialized
+                // and 'ptr' was allocated by 'alloc' with 'layout'
+                unsafe {
+                    drop_in_place(slice_from_raw_parts_mut(self.data, self.written));
+                    let ptr = std::ptr::NonNull::new_unchecked(self.ptr);
+                    self.alloc.dealloc(ptr, self.layout.inner())
+                }
+            }
+        }
+
+        let (ptr, layout, offset) = Self::allocate(Layout::for_value(slice), alloc);
+        // SAFETY: 'offset' is inside the allocated block
+        let data = unsafe { ptr.add(offset) } as *mut T;
+
+        let mut guard = Guard { alloc, ptr, layout, data, written: 0 };
+        for elem in slice {
+            // SAFETY: there is space for 'slice.len()' elements at 'data'
+            unsafe { data.add(guard.written).write(elem.clone()) };
+            guard.written += 1
+        }
+        std::mem::forget(guard);
+
+        // SAFETY: 'ptr' is a repr(C) node with every element initialized
+        unsafe { <Self as ReprC>::Fields::write_start(ptr, (next,)) };
+        let node = slice_from_raw_parts_mut(ptr as *mut T, slice.len()) as *mut Self;
+        // SAFETY: the node is fully initialized and 'ptr' is not null
+        let node = unsafe { NodeBox::from_raw(NonNull::new_unchecked(node)) };
+        debug_assert_eq!(Layout::for_value(&*node), layout);
+        node
+    }
+}
+
+impl Node<str> {
+    /// Builds the node directly at its final allocation, copying `text`.
+    #[inline]
+    pub fn from_str_in<A: AllocRef>(text: &str, next: Next<str>, alloc: &mut A) -> NodeBox<str> {
+        let node = Node::from_slice_in(text.as_bytes(), None, alloc);
+        let ptr = node.0.as_ptr() as *mut Self;
+        // SAFETY: 'str' has the same layout as '[u8]' and
+        // the bytes were copied from a valid 'str'
+        let mut node = unsafe { NodeBox::from_raw(NonNull::new_unchecked(ptr)) };
+        node.next = next;
+        node
+    }
 }
 
 impl<T: ?Sized + Hash> Hash for Node<T> {
@@ -54,7 +260,7 @@ impl<T: ?Sized + Hash> Hash for Node<T> {
     }
 }
 
-impl<U, T: ?Sized + PartialEq<U>> PartialEq<Node<U>> for Node<T> {
+impl<U: ?Sized, T: ?Sized + PartialEq<U>> PartialEq<Node<U>> for Node<T> {
     #[inline]
     fn eq(&self, other: &Node<U>) -> bool {
         if self.data != other.data {
@@ -0,0 +1,292 @@
+use super::list::LinkedList;
+use super::node::{Next, Node};
+
+use std::alloc::AllocRef;
+use std::cmp::Ordering;
+
+/// Stable merge of two sorted chains of nodes.
+///
+/// Equal elements are taken from `left` first.
+fn merge<T, F>(mut left: Next<T>, mut right: Next<T>, compare: &mut F) -> Next<T>
+where
+    T: ?Sized,
+    F: FnMut(&T, &T) -> Ordering
+{
+    let mut head = None;
+    let mut tail = &mut head;
+    loop {
+        let take_left = match (&left, &right) {
+            (Some(l), Some(r)) => compare(&l.data, &r.data) != Ordering::Greater,
+            (_, None) => {
+                *tail = left.take();
+                break
+            },
+            (None, _) => {
+                *tail = right.take();
+                break
+            }
+        };
+
+        let source = if take_left { &mut left } else { &mut right };
+        if let Some(mut node) = source.take() {
+            *source = node.next.take();
+            tail = &mut tail.get_or_insert(node).next
+        }
+    }
+    head
+}
+
+/// Bottom-up merge sort of a chain of nodes.
+///
+/// Each `bins[i]` holds a sorted run of `2^i` nodes, always older than
+/// the runs in lower bins, which keeps the sort stable without any
+/// recursion.
+fn merge_sort<T, F>(mut chain: Next<T>, compare: &mut F) -> Next<T>
+where
+    T: ?Sized,
+    F: FnMut(&T, &T) -> Ordering
+{
+    let mut bins: Vec<Next<T>> = Vec::new();
+
+    while let Some(mut node) = chain {
+        chain = node.next.take();
+
+        let mut carry = Some(node);
+        let mut i = 0;
+        while i < bins.len() && bins[i].is_some() {
+            carry = merge(bins[i].take(), carry, compare);
+            i += 1
+        }
+        if i == bins.len() {
+            bins.push(carry)
+        } else {
+            bins[i] = carry
+        }
+    }
+
+    bins.into_iter().fold(None, |newer, older| merge(older, newer, compare))
+}
+
+impl<T: ?Sized, A: AllocRef> LinkedList<T, A> {
+    /// Points `tail` to the last node, in linear time.
+    #[inline]
+    fn relink_tail(&mut self) {
+        let mut last = match self.head {
+            Some(ref head) => head,
+            None => return self.tail = None
+        };
+        while let Some(ref next) = last.next {
+            last = next
+        }
+        self.tail = Some(last.as_ptr())
+    }
+
+    /// Stable sort, relinking the nodes without moving any element.
+    #[inline]
+    pub fn sort_by<F: FnMut(&T, &T) -> Ordering>(&mut self, mut compare: F) {
+        self.head = merge_sort(self.head.take(), &mut compare);
+        self.relink_tail()
+    }
+
+    #[inline]
+    pub fn sort_by_key<K: Ord, F: FnMut(&T) -> K>(&mut self, mut key: F) {
+        self.sort_by(|a, b| key(a).cmp(&key(b)))
+    }
+
+    #[inline]
+    pub fn sort(&mut self)
+    where
+        T: Ord
+    {
+        self.sort_by(Ord::cmp)
+    }
+
+    /// Removes consecutive elements that `same_bucket(current, previous)`
+    /// reports as duplicates, keeping the first of each run.
+    #[inline]
+    pub fn dedup_by<F: FnMut(&mut T, &mut T) -> bool>(&mut self, mut same_bucket: F) {
+        let mut current: &mut Node<T> = match self.head {
+            Some(ref mut head) => head,
+            None => return
+        };
+
+        let mut removed = 0;
+        while let Some(ref mut next) = current.next {
+            if same_bucket(&mut next.data, &mut current.data) {
+                if let Some(node) = current.next.take() {
+                    // SAFETY: nodes are allocated by the list allocator
+                    current.next = unsafe { node.free_in(&mut self.alloc) };
+                    removed += 1
+                }
+            } else if let Some(ref mut next) = current.next {
+                current = next
+            }
+        }
+        self.tail = Some(current.as_ptr());
+        self.length -= removed
+    }
+
+    #[inline]
+    pub fn dedup_by_key<K: PartialEq, F: FnMut(&mut T) -> K>(&mut self, mut key: F) {
+        self.dedup_by(|a, b| key(a) == key(b))
+    }
+
+    #[inline]
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq
+    {
+        self.dedup_by(|a, b| a == b)
+    }
+}
+
+/// Like [`LinkedList::append`], only relinks nodes between lists using
+/// `Global`.
+impl<T: ?Sized> LinkedList<T> {
+    /// Merges the sorted list `other` into this sorted list, leaving
+    /// `other` empty.
+    ///
+    /// Equal elements from `self` come before the ones from `other`.
+    #[inline]
+    pub fn merge_sorted_by<F: FnMut(&T, &T) -> Ordering>(&mut self, other: &mut Self, mut compare: F) {
+        let (this, other_head) = (self.head.take(), other.head.take());
+        self.head = merge(this, other_head, &mut compare);
+        self.length += std::mem::replace(&mut other.length, 0);
+        other.tail = None;
+        self.relink_tail()
+    }
+
+    #[inline]
+    pub fn merge_sorted(&mut self, other: &mut Self)
+    where
+        T: Ord
+    {
+        self.merge_sorted_by(other, Ord::cmp)
+    }
+}
+
+impl<T, A: AllocRef> LinkedList<T, A> {
+    /// Inserts after every element that is not greater than `data`,
+    /// keeping a sorted list sorted.
+    #[inline]
+    pub fn insert_sorted_by<F: FnMut(&T, &T) -> Ordering>(&mut self, data: T, mut compare: F) {
+        let goes_after = |elem: &T, compare: &mut F| compare(elem, &data) != Ordering::Greater;
+
+        if !self.head().map_or(false, |head| goes_after(head, &mut compare)) {
+            return self.push_head(data)
+        }
+        if self.tail().map_or(false, |tail| goes_after(tail, &mut compare)) {
+            return self.push_tail(data)
+        }
+
+        let mut cursor = self.cursor_front_mut();
+        while cursor.peek_next().map_or(false, |next| goes_after(next, &mut compare)) {
+            cursor.move_next()
+        }
+        cursor.insert_after(data)
+    }
+
+    #[inline]
+    pub fn insert_sorted(&mut self, data: T)
+    where
+        T: Ord
+    {
+        self.insert_sorted_by(data, Ord::cmp)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LinkedList;
+
+    fn items<T: Copy>(list: &LinkedList<T>) -> Vec<T> {
+        list.iter().copied().collect()
+    }
+
+    #[test]
+    fn stable_sort() {
+        let pairs = vec![(3, 'a'), (1, 'b'), (2, 'c'), (1, 'd'), (3, 'e'), (0, 'f'), (2, 'g')];
+        let mut list: LinkedList<_> = pairs.iter().copied().collect();
+        list.sort_by_key(|&(key, _)| key);
+
+        let mut expected = pairs;
+        expected.sort_by_key(|&(key, _)| key);
+        assert_eq!(items(&list), expected);
+        assert_eq!(list.tail(), Some(&(3, 'e')));
+        assert_eq!(list.len(), 7);
+
+        list.push_tail((4, 'h'));
+        assert_eq!(list.tail(), Some(&(4, 'h')));
+
+        let mut empty: LinkedList<i32> = LinkedList::new();
+        empty.sort();
+        assert_eq!(empty.tail(), None)
+    }
+
+    #[test]
+    fn long_sort() {
+        let mut list: LinkedList<_> = (0..100_000).map(|i| (i * 7919) % 100_003).collect();
+        list.sort();
+        let sorted = items(&list);
+        assert!(sorted.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert_eq!(sorted.len(), 100_000);
+        assert_eq!(list.tail(), sorted.last())
+    }
+
+    #[test]
+    fn unsized_sort() {
+        let mut list: LinkedList<str> = LinkedList::new();
+        for text in &["pear", "fig", "apple", "kiwi"] {
+            list.push_tail_boxed((*text).into())
+        }
+        list.sort_by_key(str::len);
+        assert_eq!(list.iter().collect::<Vec<_>>(), vec!["fig", "pear", "kiwi", "apple"]);
+        assert_eq!(list.tail(), Some("apple"));
+
+        list.sort();
+        assert_eq!(list.iter().collect::<Vec<_>>(), vec!["apple", "fig", "kiwi", "pear"]);
+        assert_eq!(list.tail(), Some("pear"))
+    }
+
+    #[test]
+    fn sorted_merge_and_insert() {
+        let mut list: LinkedList<_> = vec![1, 4, 6].into_iter().collect();
+        let mut other: LinkedList<_> = vec![2, 4, 7, 9].into_iter().collect();
+
+        list.merge_sorted(&mut other);
+        assert_eq!(items(&list), vec![1, 2, 4, 4, 6, 7, 9]);
+        assert_eq!(list.tail(), Some(&9));
+        assert_eq!(list.len(), 7);
+        assert!(other.is_empty());
+        assert_eq!(other.tail(), None);
+
+        list.insert_sorted(0);
+        list.insert_sorted(5);
+        list.insert_sorted(10);
+        list.insert_sorted(4);
+        assert_eq!(items(&list), vec![0, 1, 2, 4, 4, 4, 5, 6, 7, 9, 10]);
+        assert_eq!(list.tail(), Some(&10));
+        assert_eq!(list.len(), 11);
+
+        let mut pairs = LinkedList::new();
+        for &pair in &[(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd')] {
+            pairs.insert_sorted_by(pair, |a, b| a.0.cmp(&b.0))
+        }
+        assert_eq!(items(&pairs), vec![(1, 'b'), (1, 'd'), (2, 'a'), (2, 'c')])
+    }
+
+    #[test]
+    fn consecutive_dedup() {
+        let mut list: LinkedList<_> = vec![1, 1, 2, 3, 3, 3, 1, 4, 4].into_iter().collect();
+        list.dedup();
+        assert_eq!(items(&list), vec![1, 2, 3, 1, 4]);
+        assert_eq!(list.tail(), Some(&4));
+        assert_eq!(list.len(), 5);
+
+        list.dedup_by_key(|x| *x / 2);
+        assert_eq!(items(&list), vec![1, 2, 1, 4]);
+
+        list.push_tail(8);
+        assert_eq!(items(&list), vec![1, 2, 1, 4, 8])
+    }
+}
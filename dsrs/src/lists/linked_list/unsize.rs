@@ -0,0 +1,168 @@
+use super::list::LinkedList;
+use super::node::Node;
+
+use std::alloc::AllocRef;
+use std::marker::Unsize;
+
+impl<T: ?Sized, A: AllocRef> LinkedList<T, A> {
+    /// Pushes a sized value as an unsized element, like `dyn Trait`,
+    /// with a single allocation.
+    #[inline]
+    pub fn push_head_dyn<U: Unsize<T>>(&mut self, data: U) {
+        let node = Node::unsize_in(data, None, &mut self.alloc);
+        // SAFETY: next is None
+        unsafe { self.push_node_head(node) }
+    }
+
+    /// Pushes a sized value as an unsized element, like `dyn Trait`,
+    /// with a single allocation.
+    #[inline]
+    pub fn push_tail_dyn<U: Unsize<T>>(&mut self, data: U) {
+        let node = Node::unsize_in(data, None, &mut self.alloc);
+        // SAFETY: next is None
+        unsafe { self.push_node_tail(node) }
+    }
+}
+
+impl<T: Clone, A: AllocRef> LinkedList<[T], A> {
+    /// Clones the slice directly into a new node.
+    #[inline]
+    pub fn push_head_slice(&mut self, data: &[T]) {
+        let node = Node::from_slice_in(data, None, &mut self.alloc);
+        // SAFETY: next is None
+        unsafe { self.push_node_head(node) }
+    }
+
+    /// Clones the slice directly into a new node.
+    #[inline]
+    pub fn push_tail_slice(&mut self, data: &[T]) {
+        let node = Node::from_slice_in(data, None, &mut self.alloc);
+        // SAFETY: next is None
+        unsafe { self.push_node_tail(node) }
+    }
+}
+
+impl<T> LinkedList<[T]> {
+    #[must_use]
+    #[inline]
+    pub fn pop_slice(&mut self) -> Option<Box<[T]>> {
+        self.pop_boxed()
+    }
+
+    /// Takes linear time, as the list has no back links.
+    #[must_use]
+    #[inline]
+    pub fn pop_tail_slice(&mut self) -> Option<Box<[T]>> {
+        self.pop_tail_boxed()
+    }
+}
+
+impl<A: AllocRef> LinkedList<str, A> {
+    /// Copies the text directly into a new node.
+    #[inline]
+    pub fn push_head_str(&mut self, data: &str) {
+        let node = Node::from_str_in(data, None, &mut self.alloc);
+        // SAFETY: next is None
+        unsafe { self.push_node_head(node) }
+    }
+
+    /// Copies the text directly into a new node.
+    #[inline]
+    pub fn push_tail_str(&mut self, data: &str) {
+        let node = Node::from_str_in(data, None, &mut self.alloc);
+        // SAFETY: next is None
+        unsafe { self.push_node_tail(node) }
+    }
+}
+
+impl LinkedList<str> {
+    #[must_use]
+    #[inline]
+    pub fn pop_str(&mut self) -> Option<Box<str>> {
+        self.pop_boxed()
+    }
+
+    /// Takes linear time, as the list has no back links.
+    #[must_use]
+    #[inline]
+    pub fn pop_tail_str(&mut self) -> Option<Box<str>> {
+        self.pop_tail_boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LinkedList;
+
+    use std::fmt::Display;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    use std::rc::Rc;
+
+    #[test]
+    fn text_nodes() {
+        let mut list: LinkedList<str> = LinkedList::new();
+        list.push_tail_str("middle");
+        list.push_head_str("");
+        list.push_tail_str("last");
+        assert_eq!(list.to_string(), "[, middle, last]");
+        assert_eq!(list.len(), 3);
+
+        assert_eq!(list.pop_tail_str().as_deref(), Some("last"));
+        assert_eq!(list.pop_str().as_deref(), Some(""));
+        assert_eq!(list.tail(), Some("middle"))
+    }
+
+    #[test]
+    fn slice_nodes() {
+        let shared = Rc::new(5);
+        let mut list: LinkedList<[Rc<i32>]> = LinkedList::new();
+        list.push_tail_slice(&[shared.clone(), shared.clone()]);
+        list.push_head_slice(&[]);
+        list.push_tail_slice(&[Rc::clone(&shared)]);
+        assert_eq!(Rc::strong_count(&shared), 4);
+
+        let lengths: Vec<_> = list.iter().map(<[_]>::len).collect();
+        assert_eq!(lengths, vec![0, 2, 1]);
+        assert_eq!(list.pop_tail_slice().map(|slice| slice.len()), Some(1));
+        assert_eq!(list.pop_slice().map(|slice| slice.len()), Some(0));
+
+        drop(list);
+        assert_eq!(Rc::strong_count(&shared), 1)
+    }
+
+    #[test]
+    fn panicking_clone() {
+        struct Bomb(Rc<()>, bool);
+
+        impl Clone for Bomb {
+            fn clone(&self) -> Self {
+                assert!(!self.1, "boom");
+                Self(self.0.clone(), self.1)
+            }
+        }
+
+        let counter = Rc::new(());
+        let bombs = [Bomb(counter.clone(), false), Bomb(counter.clone(), true)];
+
+        let mut list = LinkedList::new();
+        let result = catch_unwind(AssertUnwindSafe(|| list.push_tail_slice(&bombs)));
+        assert!(result.is_err());
+        assert!(list.is_empty());
+
+        drop(bombs);
+        assert_eq!(Rc::strong_count(&counter), 1)
+    }
+
+    #[test]
+    fn dyn_nodes() {
+        let mut list: LinkedList<dyn Display> = LinkedList::new();
+        list.push_tail_dyn(1);
+        list.push_tail_dyn("two");
+        list.push_head_dyn(0.5);
+        list.push_tail_dyn(String::from("three"));
+
+        assert_eq!(list.to_string(), "[0.5, 1, two, three]");
+        assert_eq!(list.pop_tail_boxed().map(|last| last.to_string()), Some("three".into()));
+        assert_eq!(list.len(), 3)
+    }
+}
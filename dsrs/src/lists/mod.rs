@@ -1,3 +1,11 @@
 pub mod linked_list;
+pub mod doubly_linked_list;
+pub mod circular_list;
+pub mod skip_list;
+pub mod unrolled_list;
 
 pub use linked_list::LinkedList;
+pub use doubly_linked_list::DoublyLinkedList;
+pub use circular_list::CircularList;
+pub use skip_list::{SkipMap, SkipSet};
+pub use unrolled_list::UnrolledList;
@@ -0,0 +1,782 @@
+use super::node::{Link, Node};
+use crate::rand::XorShift;
+
+use std::borrow::Borrow;
+use std::fmt::{Debug, Formatter, Result};
+use std::iter::{FromIterator, FusedIterator};
+use std::marker::PhantomData;
+use std::ops::{Bound, RangeBounds};
+
+/// Maximum number of levels, enough for far more elements than memory
+/// could hold.
+const MAX_HEIGHT: usize = 32;
+
+/// Ordered map over a probabilistic skip list.
+///
+/// Every node is on the bottom level and each level above holds about half
+/// of the nodes below it, so searches take expected logarithmic time. The
+/// heights come from a [`XorShift`] generator, which can be seeded with
+/// [`SkipMap::with_seed`] for reproducible shapes.
+pub struct SkipMap<K, V> {
+    /// First node of each level.
+    head: Vec<Link<K, V>>,
+    length: usize,
+    rng: XorShift,
+    marker: PhantomData<Box<Node<K, V>>>
+}
+
+impl<K, V> SkipMap<K, V> {
+    #[must_use]
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_rng(XorShift::from_entropy())
+    }
+
+    /// Map whose node heights are always the same for the same seed.
+    #[must_use]
+    #[inline]
+    pub const fn with_seed(seed: u64) -> Self {
+        Self::with_rng(XorShift::new(seed))
+    }
+
+    #[inline]
+    const fn with_rng(rng: XorShift) -> Self {
+        Self { head: Vec::new(), length: 0, rng, marker: PhantomData }
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.length
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Link after `node` on `level`, where `None` is the head.
+    #[inline]
+    fn link(&self, node: Link<K, V>, level: usize) -> Link<K, V> {
+        match node {
+            // SAFETY: 'ptr' is a valid node owned by the map
+            Some(ptr) => unsafe { (*ptr.as_ptr()).links[level] },
+            None => self.head[level]
+        }
+    }
+
+    /// Mutable link after `node` on `level`, where `None` is the head.
+    #[inline]
+    fn link_mut(&mut self, node: Link<K, V>, level: usize) -> &mut Link<K, V> {
+        match node {
+            // SAFETY: 'ptr' is a valid node owned by the map, which is
+            // mutably borrowed
+            Some(ptr) => unsafe { &mut (*ptr.as_ptr()).links[level] },
+            None => &mut self.head[level]
+        }
+    }
+
+    /// Last node of each level whose key is `before` the searched one.
+    #[inline]
+    fn path<F: FnMut(&K) -> bool>(&self, mut before: F) -> [Link<K, V>; MAX_HEIGHT] {
+        let mut path = [None; MAX_HEIGHT];
+        let mut node = None;
+        for level in (0..self.head.len()).rev() {
+            while let Some(next) = self.link(node, level) {
+                // SAFETY: 'next' is a valid node owned by the map
+                if !before(unsafe { &(*next.as_ptr()).key }) {
+                    break
+                }
+                node = Some(next)
+            }
+            path[level] = node
+        }
+        path
+    }
+
+    /// First node whose key is not `before` the searched one.
+    #[inline]
+    fn seek<F: FnMut(&K) -> bool>(&self, before: F) -> Link<K, V> {
+        self.seek_from(&self.path(before))
+    }
+
+    /// First node after the path, if any.
+    #[inline]
+    fn seek_from(&self, path: &[Link<K, V>]) -> Link<K, V> {
+        if self.head.is_empty() {
+            None
+        } else {
+            self.link(path[0], 0)
+        }
+    }
+
+    #[inline]
+    fn last_node(&self) -> Link<K, V> {
+        let mut node = None;
+        for level in (0..self.head.len()).rev() {
+            while let Some(next) = self.link(node, level) {
+                node = Some(next)
+            }
+        }
+        node
+    }
+
+    /// Unlinks `target`, given the nodes before it on each level.
+    #[inline]
+    fn unlink(&mut self, path: &[Link<K, V>], target: Link<K, V>) -> Option<(K, V)> {
+        let ptr = target?;
+        // SAFETY: 'ptr' is a valid node owned by the map
+        let height = unsafe { ptr.as_ref().links.len() };
+        for (level, &prev) in path.iter().enumerate().take(height) {
+            debug_assert_eq!(self.link(prev, level), target);
+            // SAFETY: same as above
+            *self.link_mut(prev, level) = unsafe { (*ptr.as_ptr()).links[level] }
+        }
+        while matches!(self.head.last(), Some(None)) {
+            self.head.pop();
+        }
+        self.length -= 1;
+        // SAFETY: 'ptr' is not linked anymore
+        Some(unsafe { Node::free(ptr) })
+    }
+
+    /// Drops every entry. Unlike other operations, this takes linear time.
+    #[inline]
+    pub fn clear(&mut self) {
+        let mut next = self.head.first().copied().flatten();
+        while let Some(ptr) = next {
+            // SAFETY: 'ptr' is a valid node owned by the map
+            next = unsafe { (*ptr.as_ptr()).links[0] };
+            // SAFETY: every node is freed exactly once, and the head is
+            // cleared below
+            unsafe { Node::free(ptr) };
+        }
+        self.head.clear();
+        self.length = 0
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn first_key_value(&self) -> Option<(&K, &V)> {
+        let ptr = self.head.first().copied().flatten()?;
+        // SAFETY: 'ptr' is a valid node owned by the map
+        let node = unsafe { &*ptr.as_ptr() };
+        Some((&node.key, &node.value))
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn last_key_value(&self) -> Option<(&K, &V)> {
+        let ptr = self.last_node()?;
+        // SAFETY: 'ptr' is a valid node owned by the map
+        let node = unsafe { &*ptr.as_ptr() };
+        Some((&node.key, &node.value))
+    }
+
+    #[inline]
+    pub fn pop_first(&mut self) -> Option<(K, V)> {
+        let target = self.head.first().copied().flatten();
+        self.unlink(&[None; MAX_HEIGHT], target)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter::new(self.head.first().copied().flatten(), self.length)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut::new(self.head.first().copied().flatten(), self.length)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys(self.iter())
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values(self.iter())
+    }
+}
+
+impl<K: Ord, V> SkipMap<K, V> {
+    /// Height for a new node, where each extra level has half the chance.
+    #[inline]
+    fn random_height(&mut self) -> usize {
+        let extra = self.rng.next_u64().trailing_ones() as usize;
+        1 + extra.min(MAX_HEIGHT - 1)
+    }
+
+    /// Node with exactly the given key.
+    #[inline]
+    fn find<Q>(&self, key: &Q) -> Link<K, V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized
+    {
+        let ptr = self.seek(|k| k.borrow() < key)?;
+        // SAFETY: 'ptr' is a valid node owned by the map
+        if unsafe { (*ptr.as_ptr()).key.borrow() } == key {
+            Some(ptr)
+        } else {
+            None
+        }
+    }
+
+    /// Inserts the entry, returning the old value when the key was
+    /// already present. In that case, the old key is kept.
+    #[inline]
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let path = self.path(|k| *k < key);
+        if let Some(ptr) = self.seek_from(&path) {
+            // SAFETY: 'ptr' is a valid node owned by the map, which is
+            // mutably borrowed
+            let node = unsafe { &mut *ptr.as_ptr() };
+            if node.key == key {
+                return Some(std::mem::replace(&mut node.value, value))
+            }
+        }
+
+        let height = self.random_height();
+        if self.head.len() < height {
+            // the path on the new levels is already the head
+            self.head.resize(height, None)
+        }
+        let ptr = Node::build(key, value, height);
+        for (level, &prev) in path.iter().enumerate().take(height) {
+            let link = self.link_mut(prev, level);
+            // SAFETY: 'ptr' was just built and is not shared yet
+            unsafe { (*ptr.as_ptr()).links[level] = *link };
+            *link = Some(ptr)
+        }
+        self.length += 1;
+        None
+    }
+
+    #[inline]
+    pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized
+    {
+        let path = self.path(|k| k.borrow() < key);
+        let ptr = self.seek_from(&path)?;
+        // SAFETY: 'ptr' is a valid node owned by the map
+        if unsafe { (*ptr.as_ptr()).key.borrow() } != key {
+            return None
+        }
+        self.unlink(&path, Some(ptr))
+    }
+
+    #[inline]
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized
+    {
+        self.remove_entry(key).map(|(_, value)| value)
+    }
+
+    #[inline]
+    pub fn pop_last(&mut self) -> Option<(K, V)> {
+        let ptr = self.last_node()?;
+        // SAFETY: 'ptr' is a valid node owned by the map and its key
+        // is not touched while searching
+        let last = unsafe { &(*ptr.as_ptr()).key };
+        let path = self.path(|k| k < last);
+        self.unlink(&path, Some(ptr))
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized
+    {
+        self.get_key_value(key).map(|(_, value)| value)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized
+    {
+        let ptr = self.find(key)?;
+        // SAFETY: 'ptr' is a valid node owned by the map
+        let node = unsafe { &*ptr.as_ptr() };
+        Some((&node.key, &node.value))
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized
+    {
+        let ptr = self.find(key)?;
+        // SAFETY: 'ptr' is a valid node owned by the map, which is
+        // mutably borrowed
+        Some(unsafe { &mut (*ptr.as_ptr()).value })
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized
+    {
+        self.find(key).is_some()
+    }
+
+    /// Iterates over the entries with keys inside `range`, in order.
+    ///
+    /// The iterator is empty if the range starts after its end.
+    #[must_use]
+    #[inline]
+    pub fn range<Q, R>(&self, range: R) -> Range<'_, K, V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+        R: RangeBounds<Q>
+    {
+        let start = match range.start_bound() {
+            Bound::Included(start) => self.seek(|k| k.borrow() < start),
+            Bound::Excluded(start) => self.seek(|k| k.borrow() <= start),
+            Bound::Unbounded => self.head.first().copied().flatten()
+        };
+        let end = match range.end_bound() {
+            Bound::Included(end) => self.seek(|k| k.borrow() <= end),
+            Bound::Excluded(end) => self.seek(|k| k.borrow() < end),
+            Bound::Unbounded => None
+        };
+
+        if let (Some(first), Some(last)) = (start, end) {
+            // SAFETY: both are valid nodes owned by the map
+            if unsafe { (*first.as_ptr()).key > (*last.as_ptr()).key } {
+                return Range::new(end, end)
+            }
+        }
+        Range::new(start, end)
+    }
+}
+
+impl<K, V> Drop for SkipMap<K, V> {
+    #[inline]
+    fn drop(&mut self) {
+        self.clear()
+    }
+}
+
+impl<K, V> Default for SkipMap<K, V> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Debug, V: Debug> Debug for SkipMap<K, V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: Ord + Clone, V: Clone> Clone for SkipMap<K, V> {
+    /// The clone keeps the generator state, so it grows the same way.
+    #[inline]
+    fn clone(&self) -> Self {
+        let mut map = Self::with_rng(self.rng.clone());
+        map.extend(self.iter().map(|(key, value)| (key.clone(), value.clone())));
+        map
+    }
+}
+
+impl<K: PartialEq, V: PartialEq> PartialEq for SkipMap<K, V> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<K: Eq, V: Eq> Eq for SkipMap<K, V> {}
+
+impl<K: Ord, V> FromIterator<(K, V)> for SkipMap<K, V> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K: Ord, V> Extend<(K, V)> for SkipMap<K, V> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+// SAFETY: the nodes are only reachable through the head links of the
+// map, so sending it moves every entry along, which needs both 'Send'
+unsafe impl<K: Send, V: Send> Send for SkipMap<K, V> {}
+// SAFETY: shared access only follows the links and gives out '&K' and
+// '&V', while the generator is only used through '&mut SkipMap'
+unsafe impl<K: Sync, V: Sync> Sync for SkipMap<K, V> {}
+
+pub struct Iter<'a, K, V> {
+    next: Link<K, V>,
+    remaining: usize,
+    marker: PhantomData<&'a Node<K, V>>
+}
+
+impl<K, V> Iter<'_, K, V> {
+    #[inline]
+    const fn new(first: Link<K, V>, length: usize) -> Self {
+        Self { next: first, remaining: length, marker: PhantomData }
+    }
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<(&'a K, &'a V)> {
+        self.next.map(|ptr| {
+            // SAFETY: 'ptr' is a valid node of a borrowed map
+            let node = unsafe { &*ptr.as_ptr() };
+            self.next = node.links[0];
+            self.remaining -= 1;
+            (&node.key, &node.value)
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
+
+impl<K, V> FusedIterator for Iter<'_, K, V> {}
+
+impl<K, V> Clone for Iter<'_, K, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.next, self.remaining)
+    }
+}
+
+pub struct IterMut<'a, K, V> {
+    next: Link<K, V>,
+    remaining: usize,
+    marker: PhantomData<&'a mut Node<K, V>>
+}
+
+impl<K, V> IterMut<'_, K, V> {
+    #[inline]
+    const fn new(first: Link<K, V>, length: usize) -> Self {
+        Self { next: first, remaining: length, marker: PhantomData }
+    }
+}
+
+impl<'a, K, V> Iterator for IterMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    #[inline]
+    fn next(&mut self) -> Option<(&'a K, &'a mut V)> {
+        self.next.map(|ptr| {
+            // SAFETY: 'ptr' is a valid node of a mutably borrowed map,
+            // visited only once
+            let node = unsafe { &mut *ptr.as_ptr() };
+            self.next = node.links[0];
+            self.remaining -= 1;
+            (&node.key, &mut node.value)
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K, V> ExactSizeIterator for IterMut<'_, K, V> {}
+
+impl<K, V> FusedIterator for IterMut<'_, K, V> {}
+
+pub struct Keys<'a, K, V>(Iter<'a, K, V>);
+
+impl<'a, K, V> Iterator for Keys<'a, K, V> {
+    type Item = &'a K;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a K> {
+        self.0.next().map(|(key, _)| key)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<K, V> ExactSizeIterator for Keys<'_, K, V> {}
+
+impl<K, V> FusedIterator for Keys<'_, K, V> {}
+
+impl<K, V> Clone for Keys<'_, K, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+pub struct Values<'a, K, V>(Iter<'a, K, V>);
+
+impl<'a, K, V> Iterator for Values<'a, K, V> {
+    type Item = &'a V;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a V> {
+        self.0.next().map(|(_, value)| value)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<K, V> ExactSizeIterator for Values<'_, K, V> {}
+
+impl<K, V> FusedIterator for Values<'_, K, V> {}
+
+impl<K, V> Clone for Values<'_, K, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+/// Entries from a node up to, but not including, another one.
+pub struct Range<'a, K, V> {
+    next: Link<K, V>,
+    end: Link<K, V>,
+    marker: PhantomData<&'a Node<K, V>>
+}
+
+impl<K, V> Range<'_, K, V> {
+    #[inline]
+    const fn new(start: Link<K, V>, end: Link<K, V>) -> Self {
+        Self { next: start, end, marker: PhantomData }
+    }
+}
+
+impl<'a, K, V> Iterator for Range<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<(&'a K, &'a V)> {
+        if self.next == self.end {
+            return None
+        }
+        self.next.map(|ptr| {
+            // SAFETY: 'ptr' is a valid node of a borrowed map
+            let node = unsafe { &*ptr.as_ptr() };
+            self.next = node.links[0];
+            (&node.key, &node.value)
+        })
+    }
+}
+
+impl<K, V> FusedIterator for Range<'_, K, V> {}
+
+impl<K, V> Clone for Range<'_, K, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.next, self.end)
+    }
+}
+
+pub struct IntoIter<K, V> {
+    map: SkipMap<K, V>
+}
+
+impl<K, V> Iterator for IntoIter<K, V> {
+    type Item = (K, V);
+
+    #[inline]
+    fn next(&mut self) -> Option<(K, V)> {
+        self.map.pop_first()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.map.len(), Some(self.map.len()))
+    }
+}
+
+impl<K, V> ExactSizeIterator for IntoIter<K, V> {}
+
+impl<K, V> FusedIterator for IntoIter<K, V> {}
+
+impl<K, V> IntoIterator for SkipMap<K, V> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V>;
+
+    #[inline]
+    fn into_iter(self) -> IntoIter<K, V> {
+        IntoIter { map: self }
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a SkipMap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a mut SkipMap<K, V> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> IterMut<'a, K, V> {
+        self.iter_mut()
+    }
+}
+
+// SAFETY: 'Iter' follows the bottom links of a borrowed map, giving out
+// '&K' and '&V', so another thread may hold it when both are 'Sync'
+unsafe impl<K: Sync, V: Sync> Send for Iter<'_, K, V> {}
+// SAFETY: a shared 'Iter' can only be cloned, giving out the same entries
+unsafe impl<K: Sync, V: Sync> Sync for Iter<'_, K, V> {}
+// SAFETY: 'IterMut' gives out '&K' with a unique '&mut V', once each, so
+// it can move when 'K: Sync' and 'V: Send'
+unsafe impl<K: Sync, V: Send> Send for IterMut<'_, K, V> {}
+// SAFETY: a shared 'IterMut' can't advance, so like '&mut V' it only
+// needs both to be 'Sync'
+unsafe impl<K: Sync, V: Sync> Sync for IterMut<'_, K, V> {}
+// SAFETY: 'Range' reads the bottom links of a borrowed map until 'end',
+// giving out '&K' and '&V', which needs both to be 'Sync'
+unsafe impl<K: Sync, V: Sync> Send for Range<'_, K, V> {}
+// SAFETY: a shared 'Range' can only be cloned, giving out the same entries
+unsafe impl<K: Sync, V: Sync> Sync for Range<'_, K, V> {}
+
+#[cfg(test)]
+mod tests {
+    use super::SkipMap;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn ordered_entries() {
+        let mut map = SkipMap::with_seed(7);
+        let mut expected = BTreeMap::new();
+        for i in 0..500_u32 {
+            let key = (i * 7919) % 1009;
+            assert_eq!(map.insert(key, i), expected.insert(key, i))
+        }
+        for i in 0..300_u32 {
+            let key = (i * 31) % 1009;
+            assert_eq!(map.remove(&key), expected.remove(&key))
+        }
+        assert_eq!(map.len(), expected.len());
+        assert!(map.iter().eq(expected.iter()));
+        assert_eq!(map.first_key_value(), expected.iter().next());
+        assert_eq!(map.last_key_value(), expected.iter().next_back());
+
+        if let Some(value) = map.get_mut(&998) {
+            *value = 0
+        }
+        assert_eq!(map.get(&998), Some(&0));
+        assert!(!map.contains_key(&31));
+        assert_eq!(map.keys().len(), map.len());
+        assert_eq!(map.values().count(), map.len())
+    }
+
+    #[test]
+    fn range_queries() {
+        use std::ops::Bound::{Excluded, Included};
+
+        let map: SkipMap<_, _> = (0..20).map(|i| (i * 5, i)).collect();
+        let keys = |range: super::Range<'_, i32, i32>| range.map(|(&k, _)| k).collect::<Vec<_>>();
+
+        assert_eq!(keys(map.range(10..25)), vec![10, 15, 20]);
+        assert_eq!(keys(map.range(11..=25)), vec![15, 20, 25]);
+        assert_eq!(keys(map.range(..12)), vec![0, 5, 10]);
+        assert_eq!(keys(map.range(88..)), vec![90, 95]);
+        assert_eq!(keys(map.range(41..44)), vec![]);
+        assert_eq!(keys(map.range((Included(50), Excluded(20)))), vec![]);
+        assert_eq!(map.range(..).count(), 20);
+        assert_eq!(keys(map.range((Excluded(10), Excluded(20)))), vec![15])
+    }
+
+    #[test]
+    fn ends_and_owning_iteration() {
+        let mut map: SkipMap<_, _> = vec![(3, 'c'), (1, 'a'), (4, 'd'), (2, 'b')].into_iter().collect();
+        assert_eq!(map.pop_first(), Some((1, 'a')));
+        assert_eq!(map.pop_last(), Some((4, 'd')));
+        assert_eq!(map.len(), 2);
+
+        for (_, value) in &mut map {
+            *value = value.to_ascii_uppercase()
+        }
+        let copy = map.clone();
+        assert_eq!(copy, map);
+        assert_eq!(format!("{:?}", map), "{2: 'B', 3: 'C'}");
+
+        let mut iter = map.into_iter();
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.next(), Some((2, 'B')));
+        assert_eq!(iter.collect::<Vec<_>>(), vec![(3, 'C')]);
+
+        let mut empty: SkipMap<i32, i32> = SkipMap::new();
+        assert_eq!(empty.pop_last(), None);
+        assert_eq!(empty.remove(&0), None);
+        assert_eq!(empty.range(1..).next(), None)
+    }
+
+    #[test]
+    fn seeded_shape() {
+        let heights = |seed| {
+            let mut map = SkipMap::with_seed(seed);
+            map.extend((0..1000).map(|i| (i, ())));
+            map.head.len()
+        };
+        assert_eq!(heights(3), heights(3));
+        // about log2(1000) levels
+        assert!((5..20).contains(&heights(3)))
+    }
+
+    #[test]
+    fn borrowed_keys() {
+        use std::ops::Bound::{Included, Unbounded};
+
+        let mut map = SkipMap::new();
+        map.insert(String::from("pear"), 3);
+        map.insert(String::from("fig"), 1);
+        map.insert(String::from("kiwi"), 2);
+
+        assert_eq!(map.get("fig"), Some(&1));
+        assert_eq!(map.remove("pear"), Some(3));
+        let after_g = (Included("g"), Unbounded);
+        assert_eq!(map.range::<str, _>(after_g).map(|(k, _)| k.as_str()).collect::<Vec<_>>(), vec!["kiwi"])
+    }
+}
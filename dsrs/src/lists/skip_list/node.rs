@@ -0,0 +1,79 @@
+use mem::alloc::Layout;
+use mem::ptr::NonNull;
+
+use std::alloc::{handle_alloc_error, AllocRef, Global};
+use std::mem::{align_of, size_of};
+use std::ptr::{self, slice_from_raw_parts_mut};
+
+pub(super) type Link<K, V> = Option<NonNull<Node<K, V>>>;
+
+#[repr(C)]
+pub(super) struct Node<K, V> {
+    pub key: K,
+    pub value: V,
+    /// Forward links, one for each level the node is in.
+    pub links: [Link<K, V>]
+}
+
+impl<K, V> Node<K, V> {
+    /// Layout of a node in `height` levels, and the offsets of its fields.
+    fn layout(height: usize) -> (Layout, [usize; 3]) {
+        let links = size_of::<Link<K, V>>()
+            .checked_mul(height)
+            .and_then(|size| Layout::from_size_align(size, align_of::<Link<K, V>>()).ok())
+            .expect("capacity overflow");
+        let fields = [Layout::new::<K>(), Layout::new::<V>(), links];
+
+        let (layout, offsets) = Layout::EMPTY.extend_many(fields).expect("capacity overflow");
+        (layout.pad_to_align(), offsets)
+    }
+
+    /// Builds an unlinked node present in `height` levels, in a single
+    /// allocation.
+    pub fn build(key: K, value: V, height: usize) -> NonNull<Self> {
+        let (layout, [key_at, value_at, _]) = Self::layout(height);
+        // the layout has at least the size of one link
+        let ptr: *mut u8 = match Global.alloc(layout.inner()) {
+            Ok(ptr) => ptr.cast().as_ptr(),
+            Err(_) => handle_alloc_error(layout.inner())
+        };
+
+        // metadata comes from the slice of links
+        let node = slice_from_raw_parts_mut(ptr, height) as *mut Self;
+        // SAFETY: the offsets are the ones of the 'repr(C)' fields, all
+        // inside the block, and the links have no drop glue, so they can
+        // be assigned over uninitialized memory
+        unsafe {
+            ptr.add(key_at).cast::<K>().write(key);
+            ptr.add(value_at).cast::<V>().write(value);
+            for level in 0..height {
+                (*node).links[level] = None
+            }
+        }
+        // SAFETY: 'node' came from a successful allocation
+        let node = unsafe { NonNull::new_unchecked(node) };
+        // SAFETY: every field is initialized
+        debug_assert_eq!(Layout::for_value(unsafe { node.as_ref() }), layout);
+        node
+    }
+
+    /// Frees a node built with [`Node::build`], returning its entry.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have been created by [`Node::build`], it must be
+    /// unlinked and must not be used after this.
+    #[inline]
+    pub unsafe fn free(ptr: NonNull<Self>) -> (K, V) {
+        // SAFETY: the caller guarantees that the node is valid, and the
+        // entry is moved out only once, since the node is freed right after,
+        // while the links have no drop glue
+        unsafe {
+            let node = &*ptr.as_ptr();
+            let layout = Layout::for_value(node);
+            let entry = (ptr::read(&node.key), ptr::read(&node.value));
+            Global.dealloc(ptr.cast().inner(), layout.inner());
+            entry
+        }
+    }
+}
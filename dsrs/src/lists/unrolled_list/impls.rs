@@ -0,0 +1,128 @@
+use super::list::UnrolledList;
+
+use std::fmt::{Debug, Formatter, Result};
+use std::hash::{Hash, Hasher};
+use std::iter::FromIterator;
+use std::ops::{Index, IndexMut};
+
+impl<T> Drop for UnrolledList<T> {
+    #[inline]
+    fn drop(&mut self) {
+        self.clear()
+    }
+}
+
+impl<T> Default for UnrolledList<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Debug> Debug for UnrolledList<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Clone> Clone for UnrolledList<T> {
+    /// The clone has the same node capacity.
+    #[inline]
+    fn clone(&self) -> Self {
+        let mut list = Self::with_node_capacity(self.node_capacity());
+        list.extend(self.iter().cloned());
+        list
+    }
+}
+
+impl<T: PartialEq> PartialEq for UnrolledList<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for UnrolledList<T> {}
+
+impl<T: Hash> Hash for UnrolledList<T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len().hash(state);
+        for elem in self {
+            elem.hash(state)
+        }
+    }
+}
+
+impl<T> Index<usize> for UnrolledList<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: usize) -> &T {
+        self.get(index).expect("index out of bounds")
+    }
+}
+
+impl<T> IndexMut<usize> for UnrolledList<T> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        self.get_mut(index).expect("index out of bounds")
+    }
+}
+
+impl<T> FromIterator<T> for UnrolledList<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T> Extend<T> for UnrolledList<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for elem in iter {
+            self.push_tail(elem)
+        }
+    }
+}
+
+impl<'a, T: 'a + Copy> Extend<&'a T> for UnrolledList<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied())
+    }
+}
+
+// SAFETY: the blocks and the elements in them are only reachable through
+// the list, so sending it moves each 'T' along, which needs 'T: Send'
+unsafe impl<T: Send> Send for UnrolledList<T> {}
+// SAFETY: splitting and merging blocks needs '&mut UnrolledList', so
+// shared access only gives out '&T', fine to share when 'T: Sync'
+unsafe impl<T: Sync> Sync for UnrolledList<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::UnrolledList;
+
+    #[test]
+    fn indexing() {
+        let mut list: UnrolledList<_> = (0..100).collect();
+        list[42] = -1;
+        assert_eq!(list[42], -1);
+        assert_eq!(list[99], 99);
+
+        let copy = list.clone();
+        assert_eq!(copy, list);
+        assert_eq!(copy.node_capacity(), list.node_capacity());
+        assert_eq!(format!("{:?}", list.iter().take(3).collect::<Vec<_>>()), "[0, 1, 2]")
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_out_of_bounds() {
+        let list: UnrolledList<_> = (0..3).collect();
+        let _ = list[3];
+    }
+}
@@ -0,0 +1,289 @@
+use super::list::UnrolledList;
+use super::node::{Link, Node};
+
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+
+/// Length of the node, which is never zero for nodes in a list.
+#[inline]
+fn node_len<T>(node: Link<T>) -> usize {
+    // SAFETY: the iterators only hold valid nodes of a borrowed list, and
+    // only the field is read, without borrowing the items
+    node.map_or(0, |ptr| unsafe { (*ptr.as_ptr()).len })
+}
+
+pub struct Iter<'a, T> {
+    front: Link<T>,
+    front_index: usize,
+    back: Link<T>,
+    back_index: usize,
+    remaining: usize,
+    marker: PhantomData<&'a Node<T>>
+}
+
+impl<T> Iter<'_, T> {
+    #[inline]
+    pub(super) fn new(head: Link<T>, tail: Link<T>, length: usize) -> Self {
+        Self {
+            front: head,
+            front_index: 0,
+            back: tail,
+            back_index: node_len(tail),
+            remaining: length,
+            marker: PhantomData
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a T> {
+        if self.remaining == 0 {
+            return None
+        }
+        if self.front_index == node_len(self.front) {
+            // SAFETY: 'front' is a valid node of a borrowed list
+            self.front = self.front.and_then(|ptr| unsafe { ptr.as_ref() }.next);
+            self.front_index = 0
+        }
+
+        // SAFETY: same as above
+        let node = unsafe { &*self.front?.as_ptr() };
+        self.front_index += 1;
+        self.remaining -= 1;
+        node.items().get(self.front_index - 1)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a T> {
+        if self.remaining == 0 {
+            return None
+        }
+        if self.back_index == 0 {
+            // SAFETY: 'back' is a valid node of a borrowed list
+            self.back = self.back.and_then(|ptr| unsafe { ptr.as_ref() }.prev);
+            self.back_index = node_len(self.back)
+        }
+
+        // SAFETY: same as above
+        let node = unsafe { &*self.back?.as_ptr() };
+        self.back_index -= 1;
+        self.remaining -= 1;
+        node.items().get(self.back_index)
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+impl<T> Clone for Iter<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self { marker: PhantomData, ..*self }
+    }
+}
+
+pub struct IterMut<'a, T> {
+    front: Link<T>,
+    front_index: usize,
+    back: Link<T>,
+    back_index: usize,
+    remaining: usize,
+    marker: PhantomData<&'a mut Node<T>>
+}
+
+impl<T> IterMut<'_, T> {
+    #[inline]
+    pub(super) fn new(head: Link<T>, tail: Link<T>, length: usize) -> Self {
+        Self {
+            front: head,
+            front_index: 0,
+            back: tail,
+            back_index: node_len(tail),
+            remaining: length,
+            marker: PhantomData
+        }
+    }
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a mut T> {
+        if self.remaining == 0 {
+            return None
+        }
+        if self.front_index == node_len(self.front) {
+            // SAFETY: 'front' is a valid node of a mutably borrowed list,
+            // and only the link is read, since its items may be borrowed
+            self.front = self.front.and_then(|ptr| unsafe { (*ptr.as_ptr()).next });
+            self.front_index = 0
+        }
+
+        let node = self.front?;
+        self.front_index += 1;
+        self.remaining -= 1;
+        // SAFETY: same as above, the item is initialized and 'remaining'
+        // ensures that each one is visited only once
+        Some(unsafe { &mut *Node::item_ptr(node, self.front_index - 1) })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a mut T> {
+        if self.remaining == 0 {
+            return None
+        }
+        if self.back_index == 0 {
+            // SAFETY: 'back' is a valid node of a mutably borrowed list,
+            // and only the link is read, since its items may be borrowed
+            self.back = self.back.and_then(|ptr| unsafe { (*ptr.as_ptr()).prev });
+            self.back_index = node_len(self.back)
+        }
+
+        let node = self.back?;
+        self.back_index -= 1;
+        self.remaining -= 1;
+        // SAFETY: same as above, the item is initialized and 'remaining'
+        // ensures that each one is visited only once
+        Some(unsafe { &mut *Node::item_ptr(node, self.back_index) })
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+impl<T> FusedIterator for IterMut<'_, T> {}
+
+pub struct IntoIter<T> {
+    list: UnrolledList<T>
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.list.pop_head()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len(), Some(self.list.len()))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        self.list.pop_tail()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> FusedIterator for IntoIter<T> {}
+
+impl<T> IntoIterator for UnrolledList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    #[inline]
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter { list: self }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a UnrolledList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut UnrolledList<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+// SAFETY: 'Iter' reads the blocks of a borrowed list, giving out only
+// '&T', so it may move to a thread that can hold '&T': 'T: Sync'
+unsafe impl<T: Sync> Send for Iter<'_, T> {}
+// SAFETY: a shared 'Iter' can only be cloned, giving out the same '&T'
+unsafe impl<T: Sync> Sync for Iter<'_, T> {}
+// SAFETY: 'IterMut' borrows one item of a block at a time and gives out
+// each '&mut T' once, so moving it needs 'T: Send'
+unsafe impl<T: Send> Send for IterMut<'_, T> {}
+// SAFETY: a shared 'IterMut' can't advance, so like '&mut T' it only
+// needs 'T: Sync'
+unsafe impl<T: Sync> Sync for IterMut<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::super::UnrolledList;
+
+    #[test]
+    fn both_ends() {
+        let mut list = UnrolledList::with_node_capacity(3);
+        list.extend(1..=8);
+
+        let mut iter = list.iter();
+        assert_eq!(iter.len(), 8);
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next_back(), Some(&8));
+        assert_eq!(iter.clone().rev().collect::<Vec<_>>(), vec![&7, &6, &5, &4, &3, &2]);
+        assert_eq!(iter.nth(2), Some(&4));
+        assert_eq!(iter.next_back(), Some(&7));
+        assert_eq!(iter.collect::<Vec<_>>(), vec![&5, &6]);
+
+        let mut iter = list.iter_mut();
+        while let (Some(front), Some(back)) = (iter.next(), iter.next_back()) {
+            std::mem::swap(front, back)
+        }
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![8, 7, 6, 5, 4, 3, 2, 1]);
+
+        let mut owned = list.into_iter();
+        assert_eq!(owned.next_back(), Some(1));
+        assert_eq!(owned.len(), 7);
+        assert_eq!(owned.collect::<Vec<_>>(), vec![8, 7, 6, 5, 4, 3, 2])
+    }
+
+    #[test]
+    fn owned_drops() {
+        let shared = std::rc::Rc::new(());
+        let mut list = UnrolledList::with_node_capacity(4);
+        for _ in 0..10 {
+            list.push_tail(std::rc::Rc::clone(&shared))
+        }
+        let mut iter = list.into_iter();
+        iter.next();
+        iter.next_back();
+        assert_eq!(std::rc::Rc::strong_count(&shared), 9);
+        drop(iter);
+        assert_eq!(std::rc::Rc::strong_count(&shared), 1)
+    }
+}
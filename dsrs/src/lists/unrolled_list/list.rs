@@ -0,0 +1,452 @@
+use super::iter::{Iter, IterMut};
+use super::node::{Link, Node};
+
+use mem::ptr::NonNull;
+use std::marker::PhantomData;
+use std::mem::size_of;
+
+/// Bytes of elements in each node, when the capacity is not given.
+const NODE_BYTES: usize = 64;
+
+/// A doubly linked list of nodes holding several elements each.
+///
+/// Every node has the same capacity and, apart from the ends, is kept at
+/// least half full, merging with its neighbour when it isn't.
+pub struct UnrolledList<T> {
+    head: Link<T>,
+    tail: Link<T>,
+    length: usize,
+    capacity: usize,
+    marker: PhantomData<Box<Node<T>>>
+}
+
+impl<T> UnrolledList<T> {
+    const DEFAULT_CAPACITY: usize = {
+        let size = if size_of::<T>() == 0 { 1 } else { size_of::<T>() };
+        let capacity = NODE_BYTES / size;
+        if capacity < 4 { 4 } else { capacity }
+    };
+
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            head: None,
+            tail: None,
+            length: 0,
+            capacity: Self::DEFAULT_CAPACITY,
+            marker: PhantomData
+        }
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    #[must_use]
+    #[inline]
+    pub fn with_node_capacity(capacity: usize) -> Self {
+        assert!(capacity > 0, "nodes must have room for some element");
+        Self { capacity, ..Self::new() }
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.length
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Number of elements each node can hold.
+    #[must_use]
+    #[inline]
+    pub const fn node_capacity(&self) -> usize {
+        self.capacity
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn head(&self) -> Option<&T> {
+        // SAFETY: 'head' is a valid node owned by the list
+        self.head.and_then(|ptr| unsafe { &*ptr.as_ptr() }.items().first())
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn head_mut(&mut self) -> Option<&mut T> {
+        // SAFETY: 'head' is a valid node owned by the list, which is
+        // mutably borrowed
+        self.head.and_then(|ptr| unsafe { &mut *ptr.as_ptr() }.items_mut().first_mut())
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn tail(&self) -> Option<&T> {
+        // SAFETY: 'tail' is a valid node owned by the list
+        self.tail.and_then(|ptr| unsafe { &*ptr.as_ptr() }.items().last())
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn tail_mut(&mut self) -> Option<&mut T> {
+        // SAFETY: 'tail' is a valid node owned by the list, which is
+        // mutably borrowed
+        self.tail.and_then(|ptr| unsafe { &mut *ptr.as_ptr() }.items_mut().last_mut())
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter::new(self.head, self.tail, self.length)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut::new(self.head, self.tail, self.length)
+    }
+
+    /// Node with the element at `index` and its position in the node,
+    /// searching from the closest end.
+    const fn locate(&self, index: usize) -> Option<(NonNull<Node<T>>, usize)> {
+        if index >= self.length {
+            return None
+        }
+
+        if index < self.length / 2 {
+            let (mut node, mut index) = (self.head, index);
+            while let Some(ptr) = node {
+                // SAFETY: 'ptr' is a valid node owned by the list
+                let node_ref = unsafe { ptr.as_ref() };
+                if index < node_ref.len {
+                    return Some((ptr, index))
+                }
+                index -= node_ref.len;
+                node = node_ref.next
+            }
+        } else {
+            // counting from the back, starting at 1
+            let (mut node, mut rest) = (self.tail, self.length - index);
+            while let Some(ptr) = node {
+                // SAFETY: 'ptr' is a valid node owned by the list
+                let node_ref = unsafe { ptr.as_ref() };
+                if rest <= node_ref.len {
+                    return Some((ptr, node_ref.len - rest))
+                }
+                rest -= node_ref.len;
+                node = node_ref.prev
+            }
+        }
+        None
+    }
+
+    /// Links a new node after `prev`, or as the head when it is `None`.
+    fn link_after(&mut self, prev: Link<T>, mut node: NonNull<Node<T>>) {
+        // SAFETY: all nodes are valid and owned by the list
+        unsafe {
+            let next = match prev {
+                Some(mut prev) => prev.as_mut().next.replace(node),
+                None => self.head.replace(node)
+            };
+            match next {
+                Some(mut next) => next.as_mut().prev = Some(node),
+                None => self.tail = Some(node)
+            }
+            node.as_mut().prev = prev;
+            node.as_mut().next = next
+        }
+    }
+
+    /// Unlinks and frees a node, dropping any element left in it.
+    fn free_node(&mut self, node: NonNull<Node<T>>) {
+        // SAFETY: all nodes are valid and owned by the list
+        unsafe {
+            let (prev, next) = (node.as_ref().prev, node.as_ref().next);
+            match prev {
+                Some(mut prev) => prev.as_mut().next = next,
+                None => self.head = next
+            }
+            match next {
+                Some(mut next) => next.as_mut().prev = prev,
+                None => self.tail = prev
+            }
+            Node::free(node)
+        }
+    }
+
+    /// Refills a node after a removal, freeing it if empty, or merging with
+    /// the next one when less than half full.
+    fn rebalance(&mut self, ptr: NonNull<Node<T>>) {
+        // SAFETY: 'ptr' is a valid node owned by the list
+        let node = unsafe { &mut *ptr.as_ptr() };
+        if node.len == 0 {
+            return self.free_node(ptr)
+        }
+        if node.len >= self.capacity / 2 {
+            return
+        }
+
+        if let Some(next_ptr) = node.next {
+            // SAFETY: 'next_ptr' is another valid node owned by the list
+            let next = unsafe { &mut *next_ptr.as_ptr() };
+            if node.len + next.len <= self.capacity {
+                next.move_tail_to(0, node);
+                self.free_node(next_ptr)
+            } else {
+                node.insert(node.len, next.remove(0))
+            }
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        let (ptr, offset) = self.locate(index)?;
+        // SAFETY: 'ptr' is a valid node owned by the list
+        Some(&unsafe { &*ptr.as_ptr() }.items()[offset])
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let (ptr, offset) = self.locate(index)?;
+        // SAFETY: 'ptr' is a valid node owned by the list, which is
+        // mutably borrowed
+        Some(&mut unsafe { &mut *ptr.as_ptr() }.items_mut()[offset])
+    }
+
+    #[inline]
+    pub fn push_head(&mut self, data: T) {
+        let ptr = match self.head {
+            // SAFETY: 'head' is a valid node owned by the list
+            Some(head) if !unsafe { head.as_ref() }.is_full() => head,
+            _ => {
+                let node = Node::alloc(self.capacity);
+                self.link_after(None, node);
+                node
+            }
+        };
+        // SAFETY: the node is owned by the list and has room for 'data'
+        unsafe { (*ptr.as_ptr()).insert(0, data) };
+        self.length += 1
+    }
+
+    #[inline]
+    pub fn push_tail(&mut self, data: T) {
+        let ptr = match self.tail {
+            // SAFETY: 'tail' is a valid node owned by the list
+            Some(tail) if !unsafe { tail.as_ref() }.is_full() => tail,
+            _ => {
+                let node = Node::alloc(self.capacity);
+                self.link_after(self.tail, node);
+                node
+            }
+        };
+        // SAFETY: the node is owned by the list and has room for 'data'
+        let node = unsafe { &mut *ptr.as_ptr() };
+        node.insert(node.len, data);
+        self.length += 1
+    }
+
+    #[inline]
+    pub fn pop_head(&mut self) -> Option<T> {
+        let ptr = self.head?;
+        // SAFETY: 'head' is a valid node owned by the list
+        let data = unsafe { (*ptr.as_ptr()).remove(0) };
+        self.length -= 1;
+        // SAFETY: same as above
+        if unsafe { ptr.as_ref() }.len == 0 {
+            self.free_node(ptr)
+        }
+        Some(data)
+    }
+
+    #[inline]
+    pub fn pop_tail(&mut self) -> Option<T> {
+        let ptr = self.tail?;
+        // SAFETY: 'tail' is a valid node owned by the list
+        let node = unsafe { &mut *ptr.as_ptr() };
+        let data = node.remove(node.len - 1);
+        self.length -= 1;
+        if node.len == 0 {
+            self.free_node(ptr)
+        }
+        Some(data)
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    #[inline]
+    pub fn insert(&mut self, index: usize, data: T) {
+        assert!(
+            index <= self.length,
+            "insertion index (is {}) should be <= len (is {})",
+            index,
+            self.length
+        );
+        let (mut ptr, mut offset) = match self.locate(index) {
+            Some(position) => position,
+            None => return self.push_tail(data)
+        };
+
+        // SAFETY: 'ptr' is a valid node owned by the list
+        if unsafe { ptr.as_ref() }.is_full() {
+            // moves the upper half to a new node
+            let half = self.capacity / 2;
+            let new = Node::alloc(self.capacity);
+            // SAFETY: both are valid and distinct nodes
+            unsafe { (*ptr.as_ptr()).move_tail_to(half, &mut *new.as_ptr()) };
+            self.link_after(Some(ptr), new);
+
+            if offset > half {
+                ptr = new;
+                offset -= half
+            }
+        }
+        // SAFETY: the node has room for one more element
+        unsafe { (*ptr.as_ptr()).insert(offset, data) };
+        self.length += 1
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        let (ptr, offset) = self.locate(index)?;
+        // SAFETY: 'ptr' is a valid node owned by the list
+        let data = unsafe { (*ptr.as_ptr()).remove(offset) };
+        self.length -= 1;
+        self.rebalance(ptr);
+        Some(data)
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        while let Some(head) = self.head {
+            self.free_node(head)
+        }
+        self.length = 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UnrolledList;
+
+    fn items<T: Copy>(list: &UnrolledList<T>) -> Vec<T> {
+        list.iter().copied().collect()
+    }
+
+    /// Lengths of each node, from head to tail.
+    fn node_lengths<T>(list: &UnrolledList<T>) -> Vec<usize> {
+        let mut lengths = Vec::new();
+        let mut node = list.head;
+        while let Some(ptr) = node {
+            // SAFETY: nodes are valid while the list is borrowed
+            let node_ref = unsafe { ptr.as_ref() };
+            lengths.push(node_ref.len);
+            node = node_ref.next
+        }
+        lengths
+    }
+
+    #[test]
+    fn ends() {
+        let mut list = UnrolledList::with_node_capacity(3);
+        for i in 0..5 {
+            list.push_tail(i)
+        }
+        list.push_head(-1);
+        assert_eq!(items(&list), vec![-1, 0, 1, 2, 3, 4]);
+        assert_eq!(node_lengths(&list), vec![1, 3, 2]);
+        assert_eq!((list.head(), list.tail()), (Some(&-1), Some(&4)));
+
+        assert_eq!(list.pop_head(), Some(-1));
+        assert_eq!(list.pop_tail(), Some(4));
+        assert_eq!(list.pop_tail(), Some(3));
+        assert_eq!(node_lengths(&list), vec![3]);
+        if let Some(head) = list.head_mut() {
+            *head = 10
+        }
+        assert_eq!(items(&list), vec![10, 1, 2]);
+        assert_eq!(list.len(), 3);
+
+        list.clear();
+        assert_eq!(list.pop_tail(), None);
+        assert_eq!(node_lengths(&list), vec![])
+    }
+
+    #[test]
+    fn split_and_merge() {
+        let mut list: UnrolledList<_> = UnrolledList::with_node_capacity(4);
+        list.extend(0..8);
+        assert_eq!(node_lengths(&list), vec![4, 4]);
+
+        // a full node splits in half
+        list.insert(1, 10);
+        assert_eq!(node_lengths(&list), vec![3, 2, 4]);
+        list.insert(3, 11);
+        assert_eq!(items(&list), vec![0, 10, 1, 11, 2, 3, 4, 5, 6, 7]);
+        assert_eq!(node_lengths(&list), vec![3, 3, 4]);
+
+        // an underfull node takes one from the next
+        assert_eq!(list.remove(4), Some(2));
+        assert_eq!(node_lengths(&list), vec![3, 2, 4]);
+        assert_eq!(list.remove(4), Some(3));
+        assert_eq!(node_lengths(&list), vec![3, 2, 3]);
+
+        // or merges with it, when everything fits
+        assert_eq!(list.remove(0), Some(0));
+        assert_eq!(list.remove(0), Some(10));
+        assert_eq!(node_lengths(&list), vec![3, 3]);
+        assert_eq!(items(&list), vec![1, 11, 4, 5, 6, 7]);
+        assert_eq!(list.remove(6), None)
+    }
+
+    #[test]
+    fn against_vec() {
+        for &capacity in &[1, 2, 5, 16] {
+            let mut list = UnrolledList::with_node_capacity(capacity);
+            let mut expected = Vec::new();
+            for i in 0..300_usize {
+                let index = (i * 37) % (expected.len() + 1);
+                if i % 4 == 3 {
+                    assert_eq!(list.remove(index), expected.get(index).copied());
+                    if index < expected.len() {
+                        expected.remove(index);
+                    }
+                } else {
+                    list.insert(index, i);
+                    expected.insert(index, i)
+                }
+            }
+            assert_eq!(items(&list), expected);
+            assert_eq!(list.len(), expected.len());
+            assert!((0..expected.len()).all(|i| list.get(i) == Some(&expected[i])));
+            assert_eq!(list.get(expected.len()), None);
+
+            let lengths = node_lengths(&list);
+            assert_eq!(lengths.iter().sum::<usize>(), list.len());
+            assert!(lengths.iter().all(|&len| 0 < len && len <= capacity))
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn insert_out_of_bounds() {
+        let mut list: UnrolledList<_> = (0..2).collect();
+        list.insert(3, 3)
+    }
+
+    #[test]
+    fn default_capacity() {
+        assert_eq!(UnrolledList::<u8>::new().node_capacity(), 64);
+        assert_eq!(UnrolledList::<u64>::new().node_capacity(), 8);
+        assert_eq!(UnrolledList::<[u64; 10]>::new().node_capacity(), 4);
+        assert_eq!(UnrolledList::<()>::new().node_capacity(), 64)
+    }
+}
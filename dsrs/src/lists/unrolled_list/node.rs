@@ -0,0 +1,156 @@
+use mem::alloc::Layout;
+use mem::ptr::NonNull;
+
+use std::alloc::{handle_alloc_error, AllocRef, Global};
+use std::mem::{align_of, size_of, MaybeUninit};
+use std::ptr::{self, slice_from_raw_parts_mut};
+
+pub(super) type Link<T> = Option<NonNull<Node<T>>>;
+
+/// A block of elements, where only the first `len` are initialized.
+#[repr(C)]
+pub(super) struct Node<T> {
+    pub prev: Link<T>,
+    pub next: Link<T>,
+    pub len: usize,
+    items: [MaybeUninit<T>]
+}
+
+impl<T> Node<T> {
+    /// Layout of a node with room for `capacity` elements.
+    fn layout(capacity: usize) -> Layout {
+        let items = size_of::<T>()
+            .checked_mul(capacity)
+            .and_then(|size| Layout::from_size_align(size, align_of::<T>()).ok())
+            .expect("capacity overflow");
+        let link = Layout::new::<Link<T>>();
+        let fields = [link, link, Layout::new::<usize>(), items];
+
+        let (layout, _) = Layout::EMPTY.extend_many(fields).expect("capacity overflow");
+        layout.pad_to_align()
+    }
+
+    /// Allocates an empty and unlinked node.
+    pub fn alloc(capacity: usize) -> NonNull<Self> {
+        let layout = Self::layout(capacity);
+        // the layout has at least the size of the links
+        let ptr: *mut u8 = match Global.alloc(layout.inner()) {
+            Ok(ptr) => ptr.cast().as_ptr(),
+            Err(_) => handle_alloc_error(layout.inner())
+        };
+
+        // metadata comes from the slice of items
+        let node = slice_from_raw_parts_mut(ptr, capacity) as *mut Self;
+        // SAFETY: the header fields are inside the block and have no
+        // drop glue, so they can be assigned over uninitialized memory
+        unsafe {
+            (*node).prev = None;
+            (*node).next = None;
+            (*node).len = 0
+        }
+        // SAFETY: 'node' came from a successful allocation
+        let node = unsafe { NonNull::new_unchecked(node) };
+        // SAFETY: the header is initialized, and the items may be uninit
+        debug_assert_eq!(Layout::for_value(unsafe { node.as_ref() }), layout);
+        node
+    }
+
+    /// Drops the remaining elements and frees the node.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have been created by [`Node::alloc`], it must be
+    /// unlinked and must not be used after this.
+    pub unsafe fn free(ptr: NonNull<Self>) {
+        // SAFETY: the caller guarantees that the node is valid
+        let node = unsafe { &mut *ptr.as_ptr() };
+        let layout = Self::layout(node.capacity());
+        // SAFETY: only the first 'len' items are initialized
+        unsafe { ptr::drop_in_place(node.items_mut()) };
+
+        // SAFETY: the node was allocated by 'Global' with this layout
+        unsafe { Global.dealloc(ptr.cast().inner(), layout.inner()) }
+    }
+
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        self.items.len()
+    }
+
+    #[inline]
+    pub const fn is_full(&self) -> bool {
+        self.len == self.capacity()
+    }
+
+    #[inline]
+    pub fn items(&self) -> &[T] {
+        let items = &self.items[..self.len];
+        // SAFETY: the first 'len' items are initialized
+        unsafe { &*(items as *const [MaybeUninit<T>] as *const [T]) }
+    }
+
+    #[inline]
+    pub fn items_mut(&mut self) -> &mut [T] {
+        let items = &mut self.items[..self.len];
+        // SAFETY: the first 'len' items are initialized
+        unsafe { &mut *(items as *mut [MaybeUninit<T>] as *mut [T]) }
+    }
+
+    /// Pointer to the item at `index`, borrowing nothing else of the node,
+    /// so the other items can still be borrowed elsewhere.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be a valid node and `index` must be inside its capacity.
+    #[inline]
+    pub unsafe fn item_ptr(ptr: NonNull<Self>, index: usize) -> *mut T {
+        // SAFETY: guaranteed by the caller, and indexing the field in place
+        // only borrows that one item
+        unsafe { (*ptr.as_ptr()).items[index].as_mut_ptr() }
+    }
+
+    /// Start of the items, valid for the whole capacity.
+    #[inline]
+    fn items_ptr(&mut self) -> *mut T {
+        self.items.as_mut_ptr().cast()
+    }
+
+    /// Inserts at `index`, shifting the following items to the right.
+    pub fn insert(&mut self, index: usize, data: T) {
+        assert!(index <= self.len && !self.is_full());
+        let ptr = self.items_ptr();
+        // SAFETY: there is room for one more item, and both ranges are
+        // inside the capacity
+        unsafe {
+            ptr::copy(ptr.add(index), ptr.add(index + 1), self.len - index);
+            ptr.add(index).write(data)
+        }
+        self.len += 1
+    }
+
+    /// Removes at `index`, shifting the following items to the left.
+    pub fn remove(&mut self, index: usize) -> T {
+        assert!(index < self.len);
+        let ptr = self.items_ptr();
+        self.len -= 1;
+        // SAFETY: 'index' is initialized and is moved out before being
+        // overwritten by the shift
+        unsafe {
+            let data = ptr.add(index).read();
+            ptr::copy(ptr.add(index + 1), ptr.add(index), self.len - index);
+            data
+        }
+    }
+
+    /// Moves the items from `at` onwards to the end of `other`.
+    pub fn move_tail_to(&mut self, at: usize, other: &mut Self) {
+        let count = self.len - at;
+        assert!(other.len + count <= other.capacity());
+        let (src, dst) = (self.items_ptr(), other.items_ptr());
+        // SAFETY: the items are moved between different nodes and
+        // stay inside their capacities
+        unsafe { ptr::copy_nonoverlapping(src.add(at), dst.add(other.len), count) };
+        self.len = at;
+        other.len += count
+    }
+}
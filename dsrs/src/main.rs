@@ -20,7 +20,7 @@ fn main() {
     println!("{:?}", list.tail());
     println!("{:?}", list);
 
-    while let Some(elem) = list.next() {
+    while let Some(elem) = list.pop() {
         println!("{:?}", elem)
     }
     println!("{:?}", list)
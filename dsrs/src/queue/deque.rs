@@ -0,0 +1,412 @@
+use super::iter::{Iter, IterMut};
+use crate::vec::RawBuf;
+
+use std::ptr;
+use std::slice;
+
+/// Smallest capacity allocated on growth.
+const MIN_CAPACITY: usize = 4;
+
+/// A double-ended queue in a growable ring buffer.
+///
+/// The elements start at `head` and may wrap around the end of the buffer,
+/// which doubles in place whenever the allocator can.
+pub struct Deque<T> {
+    buf: RawBuf<T>,
+    head: usize,
+    length: usize
+}
+
+impl<T> Deque<T> {
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self { buf: RawBuf::new(), head: 0, length: 0 }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut deque = Self::new();
+        deque.reserve(capacity);
+        deque
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.length
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        self.buf.capacity()
+    }
+
+    /// Position in the buffer of the element at `index`, which may go up
+    /// to the capacity.
+    #[inline]
+    const fn physical(&self, index: usize) -> usize {
+        // avoids overflowing for zero-sized types
+        let before_end = self.capacity() - self.head;
+        if index < before_end { self.head + index } else { index - before_end }
+    }
+
+    #[inline]
+    fn ptr_at(&self, index: usize) -> *mut T {
+        // SAFETY: physical positions are inside the buffer
+        unsafe { self.buf.as_ptr().add(self.physical(index)) }
+    }
+
+    /// Room for at least `additional` more elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the capacity overflows.
+    pub fn reserve(&mut self, additional: usize) {
+        let old = self.capacity();
+        let required = self.length.checked_add(additional).expect("capacity overflow");
+        if required <= old {
+            return
+        }
+        let capacity = required.max(old.saturating_mul(2)).max(MIN_CAPACITY);
+        self.buf.resize(capacity);
+
+        // the wrapped part must be moved out of the old end
+        if self.head + self.length > old {
+            let head_len = old - self.head;
+            let tail_len = self.length - head_len;
+            let ptr = self.buf.as_ptr();
+            if tail_len <= head_len && tail_len <= capacity - old {
+                // SAFETY: the wrapped elements fit right after the old end
+                unsafe { ptr::copy_nonoverlapping(ptr, ptr.add(old), tail_len) }
+            } else {
+                let head = capacity - head_len;
+                // SAFETY: the first part is moved to the end of the new
+                // buffer, which is inside the capacity
+                unsafe { ptr::copy(ptr.add(self.head), ptr.add(head), head_len) };
+                self.head = head
+            }
+        }
+    }
+
+    /// Moves every element to the start of the buffer and frees the rest.
+    #[inline]
+    pub fn shrink_to_fit(&mut self) {
+        self.make_contiguous();
+        if self.head != 0 {
+            let ptr = self.buf.as_ptr();
+            // SAFETY: the elements don't wrap, so they are moved inside the
+            // buffer
+            unsafe { ptr::copy(ptr.add(self.head), ptr, self.length) };
+            self.head = 0
+        }
+        self.buf.resize(self.length)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index < self.length {
+            // SAFETY: the element is initialized
+            Some(unsafe { &*self.ptr_at(index) })
+        } else {
+            None
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index < self.length {
+            // SAFETY: the element is initialized and uniquely borrowed
+            Some(unsafe { &mut *self.ptr_at(index) })
+        } else {
+            None
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn head(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn head_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(0)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn tail(&self) -> Option<&T> {
+        self.get(self.length.checked_sub(1)?)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn tail_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(self.length.checked_sub(1)?)
+    }
+
+    #[inline]
+    pub fn push_head(&mut self, data: T) {
+        if self.length == self.capacity() {
+            self.reserve(1)
+        }
+        self.head = self.physical(self.capacity() - 1);
+        // SAFETY: there was room before the first element
+        unsafe { self.ptr_at(0).write(data) };
+        self.length += 1
+    }
+
+    #[inline]
+    pub fn push_tail(&mut self, data: T) {
+        if self.length == self.capacity() {
+            self.reserve(1)
+        }
+        // SAFETY: there is room after the last element
+        unsafe { self.ptr_at(self.length).write(data) };
+        self.length += 1
+    }
+
+    #[inline]
+    pub fn pop_head(&mut self) -> Option<T> {
+        if self.length == 0 {
+            return None
+        }
+        // SAFETY: the first element is initialized and is no longer
+        // considered part of the deque
+        let data = unsafe { self.ptr_at(0).read() };
+        self.head = self.physical(1);
+        self.length -= 1;
+        Some(data)
+    }
+
+    #[inline]
+    pub fn pop_tail(&mut self) -> Option<T> {
+        if self.length == 0 {
+            return None
+        }
+        self.length -= 1;
+        // SAFETY: the last element is initialized and is no longer
+        // considered part of the deque
+        Some(unsafe { self.ptr_at(self.length).read() })
+    }
+
+    /// Swaps the elements at `i` and `j`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either index is out of bounds.
+    #[inline]
+    pub fn swap(&mut self, i: usize, j: usize) {
+        assert!(i < self.length && j < self.length, "index out of bounds");
+        // SAFETY: both elements are initialized
+        unsafe { ptr::swap(self.ptr_at(i), self.ptr_at(j)) }
+    }
+
+    /// The elements from the head to the end of the buffer, and then the
+    /// ones that wrapped around.
+    #[must_use]
+    #[inline]
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let head_len = self.length.min(self.capacity() - self.head);
+        // SAFETY: both parts are initialized and don't overlap
+        unsafe {
+            let ptr = self.buf.as_ptr();
+            let front = slice::from_raw_parts(ptr.add(self.head), head_len);
+            let back = slice::from_raw_parts(ptr, self.length - head_len);
+            (front, back)
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let head_len = self.length.min(self.capacity() - self.head);
+        // SAFETY: both parts are initialized, don't overlap and are
+        // uniquely borrowed
+        unsafe {
+            let ptr = self.buf.as_ptr();
+            let front = slice::from_raw_parts_mut(ptr.add(self.head), head_len);
+            let back = slice::from_raw_parts_mut(ptr, self.length - head_len);
+            (front, back)
+        }
+    }
+
+    /// Rearranges the buffer so the elements don't wrap around, returning
+    /// them in order.
+    pub fn make_contiguous(&mut self) -> &mut [T] {
+        let head_len = self.capacity() - self.head;
+        if self.length > head_len {
+            let tail_len = self.length - head_len;
+            let ptr = self.buf.as_ptr();
+            // SAFETY: the first part is moved to right after the wrapped
+            // one, leaving every element in '0..length', but in rotated
+            // order
+            unsafe {
+                ptr::copy(ptr.add(self.head), ptr.add(tail_len), head_len);
+                slice::from_raw_parts_mut(ptr, self.length).rotate_left(tail_len)
+            }
+            self.head = 0
+        }
+        // SAFETY: the elements are initialized and in order
+        unsafe { slice::from_raw_parts_mut(self.ptr_at(0), self.length) }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> {
+        let (front, back) = self.as_slices();
+        Iter::new(front, back)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let (front, back) = self.as_mut_slices();
+        IterMut::new(front, back)
+    }
+
+    /// Drops every element after the first `len`.
+    pub fn truncate(&mut self, len: usize) {
+        while self.length > len {
+            drop(self.pop_tail())
+        }
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        let (front, back) = self.as_mut_slices();
+        let (front, back) = (front as *mut [T], back as *mut [T]);
+        // forget the elements first, in case a drop panics
+        self.head = 0;
+        self.length = 0;
+        // SAFETY: the elements are initialized and not reachable anymore
+        unsafe {
+            ptr::drop_in_place(front);
+            ptr::drop_in_place(back)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Deque;
+
+    #[test]
+    fn both_ends() {
+        let mut deque = Deque::new();
+        for i in 0..3 {
+            deque.push_tail(i);
+            deque.push_head(-i - 1)
+        }
+        assert_eq!(deque.capacity(), 8);
+        assert_eq!(deque.iter().copied().collect::<Vec<_>>(), vec![-3, -2, -1, 0, 1, 2]);
+        assert_eq!((deque.head(), deque.tail()), (Some(&-3), Some(&2)));
+
+        assert_eq!(deque.pop_head(), Some(-3));
+        assert_eq!(deque.pop_tail(), Some(2));
+        assert_eq!(deque.get(1), Some(&-1));
+        assert_eq!(deque.get(4), None);
+        *deque.tail_mut().unwrap() = 10;
+        assert_eq!(deque.len(), 4);
+        deque.swap(0, 3);
+        assert_eq!(deque.iter().copied().collect::<Vec<_>>(), vec![10, -1, 0, -2]);
+
+        while deque.pop_tail().is_some() {}
+        assert!(deque.is_empty());
+        assert_eq!(deque.pop_head(), None)
+    }
+
+    #[test]
+    fn growing_while_wrapped() {
+        let mut deque = Deque::with_capacity(4);
+        deque.extend(0..4);
+        deque.pop_head();
+        deque.pop_head();
+        deque.extend(4..6);
+        assert_eq!(deque.as_slices(), (&[2, 3][..], &[4, 5][..]));
+
+        // the short wrapped part goes after the old end
+        deque.push_tail(6);
+        assert_eq!(deque.capacity(), 8);
+        assert_eq!(deque.as_slices(), (&[2, 3, 4, 5, 6][..], &[][..]));
+
+        // and a long one leaves the first part at the new end
+        let mut deque = Deque::with_capacity(4);
+        deque.extend(0..4);
+        for i in 4..7 {
+            deque.pop_head();
+            deque.push_tail(i)
+        }
+        deque.push_tail(7);
+        assert_eq!(deque.as_slices(), (&[3][..], &[4, 5, 6, 7][..]));
+        assert_eq!(deque.iter().copied().collect::<Vec<_>>(), vec![3, 4, 5, 6, 7])
+    }
+
+    #[test]
+    fn contiguous() {
+        let mut deque = Deque::with_capacity(8);
+        deque.extend(0..5);
+        for _ in 0..4 {
+            let first = deque.pop_head().unwrap();
+            deque.push_tail(first + 5)
+        }
+        assert_eq!(deque.as_slices(), (&[4, 5, 6, 7][..], &[8][..]));
+        assert_eq!(deque.make_contiguous(), &mut [4, 5, 6, 7, 8]);
+        assert_eq!(deque.as_slices(), (&[4, 5, 6, 7, 8][..], &[][..]));
+
+        deque.push_head(3);
+        deque.shrink_to_fit();
+        assert_eq!(deque.capacity(), 6);
+        assert_eq!(deque.make_contiguous(), &mut [3, 4, 5, 6, 7, 8]);
+
+        // the elements don't wrap, but don't start at the buffer either
+        let mut deque = Deque::with_capacity(8);
+        deque.extend(0..5);
+        deque.pop_head();
+        deque.pop_head();
+        deque.shrink_to_fit();
+        assert_eq!(deque.capacity(), 3);
+        assert_eq!(deque.as_slices(), (&[2, 3, 4][..], &[][..]))
+    }
+
+    #[test]
+    fn zero_sized() {
+        let mut deque = Deque::new();
+        for _ in 0..100 {
+            deque.push_head(());
+            deque.push_tail(())
+        }
+        assert_eq!(deque.len(), 200);
+        assert_eq!(deque.pop_head(), Some(()));
+        assert_eq!(deque.make_contiguous().len(), 199);
+        assert_eq!(deque.into_iter().count(), 199)
+    }
+
+    #[test]
+    fn drops() {
+        use std::rc::Rc;
+
+        let shared = Rc::new(());
+        let mut deque = Deque::with_capacity(4);
+        for _ in 0..3 {
+            deque.push_head(Rc::clone(&shared))
+        }
+        deque.push_tail(Rc::clone(&shared));
+        deque.truncate(3);
+        assert_eq!(Rc::strong_count(&shared), 4);
+        drop(deque);
+        assert_eq!(Rc::strong_count(&shared), 1)
+    }
+}
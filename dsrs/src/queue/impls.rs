@@ -0,0 +1,127 @@
+use super::deque::Deque;
+
+use std::fmt::{Debug, Formatter, Result};
+use std::hash::{Hash, Hasher};
+use std::iter::FromIterator;
+use std::ops::{Index, IndexMut};
+
+impl<T> Drop for Deque<T> {
+    #[inline]
+    fn drop(&mut self) {
+        self.clear()
+    }
+}
+
+impl<T> Default for Deque<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Debug> Debug for Deque<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Clone> Clone for Deque<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        let mut deque = Self::with_capacity(self.len());
+        deque.extend(self.iter().cloned());
+        deque
+    }
+}
+
+impl<T: PartialEq> PartialEq for Deque<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for Deque<T> {}
+
+impl<T: Hash> Hash for Deque<T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len().hash(state);
+        for elem in self {
+            elem.hash(state)
+        }
+    }
+}
+
+impl<T> Index<usize> for Deque<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: usize) -> &T {
+        self.get(index).expect("index out of bounds")
+    }
+}
+
+impl<T> IndexMut<usize> for Deque<T> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        self.get_mut(index).expect("index out of bounds")
+    }
+}
+
+impl<T> FromIterator<T> for Deque<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut deque = Self::new();
+        deque.extend(iter);
+        deque
+    }
+}
+
+impl<T> Extend<T> for Deque<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for elem in iter {
+            self.push_tail(elem)
+        }
+    }
+}
+
+impl<'a, T: 'a + Copy> Extend<&'a T> for Deque<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Deque;
+
+    #[test]
+    fn indexing() {
+        let mut deque: Deque<_> = (0..10).collect();
+        for i in 1..=5 {
+            deque.push_head(-i)
+        }
+        deque[5] = 100;
+        assert_eq!(deque[0], -5);
+        assert_eq!(deque[5], 100);
+        assert_eq!(deque[14], 9);
+
+        let copy = deque.clone();
+        assert_eq!(copy, deque);
+        deque.make_contiguous();
+        assert_eq!(copy, deque);
+        assert_eq!(format!("{:?}", deque.iter().take(3).collect::<Vec<_>>()), "[-5, -4, -3]")
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_out_of_bounds() {
+        let deque: Deque<_> = (0..3).collect();
+        let _ = deque[3];
+    }
+}
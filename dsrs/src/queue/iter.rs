@@ -0,0 +1,189 @@
+use super::deque::Deque;
+
+use std::iter::FusedIterator;
+use std::slice;
+
+/// Goes through the part before the end of the buffer, then the wrapped one.
+pub struct Iter<'a, T> {
+    front: slice::Iter<'a, T>,
+    back: slice::Iter<'a, T>
+}
+
+impl<'a, T> Iter<'a, T> {
+    #[inline]
+    pub(super) fn new(front: &'a [T], back: &'a [T]) -> Self {
+        Self { front: front.iter(), back: back.iter() }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a T> {
+        self.front.next().or_else(|| self.back.next())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.front.len() + self.back.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a T> {
+        self.back.next_back().or_else(|| self.front.next_back())
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+impl<T> Clone for Iter<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self { front: self.front.clone(), back: self.back.clone() }
+    }
+}
+
+pub struct IterMut<'a, T> {
+    front: slice::IterMut<'a, T>,
+    back: slice::IterMut<'a, T>
+}
+
+impl<'a, T> IterMut<'a, T> {
+    #[inline]
+    pub(super) fn new(front: &'a mut [T], back: &'a mut [T]) -> Self {
+        Self { front: front.iter_mut(), back: back.iter_mut() }
+    }
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a mut T> {
+        self.front.next().or_else(|| self.back.next())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.front.len() + self.back.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a mut T> {
+        self.back.next_back().or_else(|| self.front.next_back())
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+impl<T> FusedIterator for IterMut<'_, T> {}
+
+pub struct IntoIter<T> {
+    deque: Deque<T>
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.deque.pop_head()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.deque.len(), Some(self.deque.len()))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        self.deque.pop_tail()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> FusedIterator for IntoIter<T> {}
+
+impl<T> IntoIterator for Deque<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    #[inline]
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter { deque: self }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Deque<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Deque<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::Deque;
+
+    /// Like `atualizar_dados` from Lab04, updating every element in place.
+    #[test]
+    fn in_place_updates() {
+        let mut queue = Deque::with_capacity(4);
+        queue.extend(vec![("ana", 3), ("bia", 1)]);
+        queue.push_head(("caio", 2));
+        queue.push_head(("davi", 5));
+
+        for (_, turns) in &mut queue {
+            *turns -= 1
+        }
+        queue.iter_mut().rev().take(1).for_each(|(name, _)| *name = "beto");
+        let names: Vec<_> = queue.iter().map(|&(name, _)| name).collect();
+        assert_eq!(names, vec!["davi", "caio", "ana", "beto"]);
+        assert_eq!(queue.iter().map(|&(_, turns)| turns).sum::<i32>(), 7)
+    }
+
+    #[test]
+    fn both_ends() {
+        let mut deque = Deque::with_capacity(4);
+        deque.extend(3..6);
+        deque.push_head(2);
+        deque.push_head(1);
+
+        let mut iter = deque.iter();
+        assert_eq!(iter.len(), 5);
+        assert_eq!(iter.next_back(), Some(&5));
+        assert_eq!(iter.clone().collect::<Vec<_>>(), vec![&1, &2, &3, &4]);
+        assert_eq!(iter.nth(1), Some(&2));
+
+        let mut owned = deque.into_iter();
+        assert_eq!(owned.next_back(), Some(5));
+        assert_eq!(owned.next(), Some(1));
+        assert_eq!(owned.len(), 3);
+        assert_eq!(owned.collect::<Vec<_>>(), vec![2, 3, 4])
+    }
+}
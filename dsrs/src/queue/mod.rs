@@ -0,0 +1,7 @@
+//! Queues with both ends open.
+mod deque;
+mod iter;
+mod impls;
+
+pub use deque::Deque;
+pub use iter::{IntoIter, Iter, IterMut};
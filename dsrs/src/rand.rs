@@ -0,0 +1,58 @@
+//! Pseudorandom numbers for the randomized structures.
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+
+/// Xorshift64* generator.
+///
+/// Fast and good enough to balance the structures, but not for anything
+/// that really depends on randomness.
+#[derive(Debug, Clone)]
+pub struct XorShift {
+    state: u64
+}
+
+impl XorShift {
+    /// The state can never be zero.
+    const FALLBACK: u64 = 0x9E37_79B9_7F4A_7C15;
+
+    /// Same seed, same sequence.
+    #[inline]
+    pub const fn new(seed: u64) -> Self {
+        let state = if seed == 0 { Self::FALLBACK } else { seed };
+        Self { state }
+    }
+
+    /// Seeded by the random keys of the std hasher.
+    #[inline]
+    pub fn from_entropy() -> Self {
+        Self::new(RandomState::new().build_hasher().finish())
+    }
+
+    #[inline]
+    pub const fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::XorShift;
+
+    #[test]
+    fn reproducible() {
+        let mut rng = XorShift::new(42);
+        let first: Vec<_> = (0..100).map(|_| rng.next_u64()).collect();
+        let mut again = XorShift::new(42);
+        assert!(first.iter().all(|&x| x == again.next_u64()));
+
+        let mut zero = XorShift::new(0);
+        assert!((0..100).all(|_| zero.next_u64() != 0));
+
+        // roughly half of the bits set
+        let ones: u32 = first.iter().map(|x| x.count_ones()).sum();
+        assert!((3000..3400).contains(&ones))
+    }
+}
@@ -0,0 +1,76 @@
+use super::iter::InOrder;
+use super::node::{Link, Node};
+use super::tree::{BinaryTree, Order};
+use crate::vec::Vector;
+
+use std::fmt::{Debug, Formatter, Result};
+use std::iter::FromIterator;
+
+impl<T> Drop for BinaryTree<T> {
+    #[inline]
+    fn drop(&mut self) {
+        self.clear()
+    }
+}
+
+impl<T> Default for BinaryTree<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Debug> Debug for BinaryTree<T> {
+    /// The elements in order.
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Clone> Clone for BinaryTree<T> {
+    /// Copies each node in pre-order, keeping the same shape.
+    fn clone(&self) -> Self {
+        let mut tree = Self { root: None, length: self.length };
+        // each node to copy and the link that should point to its copy
+        let mut stack: Vector<(_, *mut Link<T>)> = Vector::new();
+        stack.extend(self.root.map(|ptr| (ptr, &mut tree.root as *mut _)));
+
+        while let Some((ptr, link)) = stack.pop() {
+            // SAFETY: 'ptr' is owned by 'self', while 'link' is in a node of
+            // the new tree or its root, both still alive
+            unsafe {
+                let node: &Node<T> = &*ptr.as_ptr();
+                let copy = Node::leaf(node.data.clone());
+                *link = Some(copy);
+                stack.extend(node.right.map(|ptr| (ptr, &mut (*copy.as_ptr()).right as *mut _)));
+                stack.extend(node.left.map(|ptr| (ptr, &mut (*copy.as_ptr()).left as *mut _)))
+            }
+        }
+        tree
+    }
+}
+
+impl<T> FromIterator<T> for BinaryTree<T> {
+    /// Builds a balanced tree with the elements in order.
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from_traversal(iter, Order::InOrder)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a BinaryTree<T> {
+    type Item = &'a T;
+    type IntoIter = InOrder<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> InOrder<'a, T> {
+        self.iter()
+    }
+}
+
+// SAFETY: the nodes are only reachable from the root of the tree, so
+// sending it moves every element along, which needs 'T: Send'
+unsafe impl<T: Send> Send for BinaryTree<T> {}
+// SAFETY: the traversals and queries of '&BinaryTree' only read the nodes
+// and give out '&T', fine to share when 'T: Sync'
+unsafe impl<T: Sync> Sync for BinaryTree<T> {}
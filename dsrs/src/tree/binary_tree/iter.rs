@@ -0,0 +1,304 @@
+use super::node::{Link, Node};
+use crate::queue::Deque;
+use crate::vec::Vector;
+
+use mem::ptr::NonNull;
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+
+/// A node and its distance from the root.
+type Entry<T> = (NonNull<Node<T>>, usize);
+
+/// Borrows the node of an [`Entry`].
+///
+/// # Safety
+///
+/// The node must be owned by a tree borrowed for `'a`.
+#[inline]
+unsafe fn node<'a, T>(ptr: NonNull<Node<T>>) -> &'a Node<T> {
+    // SAFETY: guaranteed by the caller
+    unsafe { &*ptr.as_ptr() }
+}
+
+/// Left subtree, then the root, then the right subtree.
+pub struct InOrder<'a, T> {
+    // the leftmost path of what is still to be visited
+    stack: Vector<Entry<T>>,
+    remaining: usize,
+    marker: PhantomData<&'a Node<T>>
+}
+
+impl<'a, T> InOrder<'a, T> {
+    #[inline]
+    pub(super) fn new(root: Link<T>, length: usize) -> Self {
+        let mut iter = Self { stack: Vector::new(), remaining: length, marker: PhantomData };
+        iter.push_leftmost(root, 0);
+        iter
+    }
+
+    #[inline]
+    fn push_leftmost(&mut self, mut link: Link<T>, mut depth: usize) {
+        while let Some(ptr) = link {
+            self.stack.push((ptr, depth));
+            // SAFETY: the tree is borrowed for 'a
+            link = unsafe { node(ptr) }.left;
+            depth += 1
+        }
+    }
+
+    /// The next element and its depth.
+    pub(super) fn next_depth(&mut self) -> Option<(&'a T, usize)> {
+        let (ptr, depth) = self.stack.pop()?;
+        // SAFETY: the tree is borrowed for 'a
+        let node = unsafe { node(ptr) };
+        self.push_leftmost(node.right, depth + 1);
+        self.remaining -= 1;
+        Some((&node.data, depth))
+    }
+}
+
+/// The root, then the left subtree, then the right subtree.
+pub struct PreOrder<'a, T> {
+    // roots of the subtrees still to be visited
+    stack: Vector<Entry<T>>,
+    remaining: usize,
+    marker: PhantomData<&'a Node<T>>
+}
+
+impl<'a, T> PreOrder<'a, T> {
+    #[inline]
+    pub(super) fn new(root: Link<T>, length: usize) -> Self {
+        let mut stack = Vector::new();
+        stack.extend(root.map(|ptr| (ptr, 0)));
+        Self { stack, remaining: length, marker: PhantomData }
+    }
+
+    /// The next element and its depth.
+    pub(super) fn next_depth(&mut self) -> Option<(&'a T, usize)> {
+        let (ptr, depth) = self.stack.pop()?;
+        // SAFETY: the tree is borrowed for 'a
+        let node = unsafe { node(ptr) };
+        self.stack.extend(node.right.map(|ptr| (ptr, depth + 1)));
+        self.stack.extend(node.left.map(|ptr| (ptr, depth + 1)));
+        self.remaining -= 1;
+        Some((&node.data, depth))
+    }
+}
+
+/// Left subtree, then the right subtree, then the root.
+pub struct PostOrder<'a, T> {
+    // subtrees still to be visited, marked when their children already
+    // were pushed after them
+    stack: Vector<(Entry<T>, bool)>,
+    remaining: usize,
+    marker: PhantomData<&'a Node<T>>
+}
+
+impl<'a, T> PostOrder<'a, T> {
+    #[inline]
+    pub(super) fn new(root: Link<T>, length: usize) -> Self {
+        let mut stack = Vector::new();
+        stack.extend(root.map(|ptr| ((ptr, 0), false)));
+        Self { stack, remaining: length, marker: PhantomData }
+    }
+
+    /// The next element and its depth.
+    pub(super) fn next_depth(&mut self) -> Option<(&'a T, usize)> {
+        loop {
+            let ((ptr, depth), expanded) = self.stack.pop()?;
+            // SAFETY: the tree is borrowed for 'a
+            let node = unsafe { node(ptr) };
+            if expanded {
+                self.remaining -= 1;
+                return Some((&node.data, depth))
+            }
+            self.stack.push(((ptr, depth), true));
+            self.stack.extend(node.right.map(|ptr| ((ptr, depth + 1), false)));
+            self.stack.extend(node.left.map(|ptr| ((ptr, depth + 1), false)))
+        }
+    }
+}
+
+/// Each level from the root down, from left to right.
+pub struct LevelOrder<'a, T> {
+    queue: Deque<Entry<T>>,
+    remaining: usize,
+    marker: PhantomData<&'a Node<T>>
+}
+
+impl<'a, T> LevelOrder<'a, T> {
+    #[inline]
+    pub(super) fn new(root: Link<T>, length: usize) -> Self {
+        let mut queue = Deque::new();
+        queue.extend(root.map(|ptr| (ptr, 0)));
+        Self { queue, remaining: length, marker: PhantomData }
+    }
+
+    /// The next element and its depth.
+    pub(super) fn next_depth(&mut self) -> Option<(&'a T, usize)> {
+        let (ptr, depth) = self.queue.pop_head()?;
+        // SAFETY: the tree is borrowed for 'a
+        let node = unsafe { node(ptr) };
+        self.queue.extend(node.left.map(|ptr| (ptr, depth + 1)));
+        self.queue.extend(node.right.map(|ptr| (ptr, depth + 1)));
+        self.remaining -= 1;
+        Some((&node.data, depth))
+    }
+}
+
+/// Any of the depth-first traversals, chosen by an [`Order`](super::Order).
+pub enum Traversal<'a, T> {
+    InOrder(InOrder<'a, T>),
+    PreOrder(PreOrder<'a, T>),
+    PostOrder(PostOrder<'a, T>)
+}
+
+impl<'a, T> Traversal<'a, T> {
+    /// The next element and its depth.
+    #[inline]
+    pub(super) fn next_depth(&mut self) -> Option<(&'a T, usize)> {
+        match self {
+            Self::InOrder(iter) => iter.next_depth(),
+            Self::PreOrder(iter) => iter.next_depth(),
+            Self::PostOrder(iter) => iter.next_depth()
+        }
+    }
+
+    #[inline]
+    const fn remaining(&self) -> usize {
+        match self {
+            Self::InOrder(iter) => iter.remaining,
+            Self::PreOrder(iter) => iter.remaining,
+            Self::PostOrder(iter) => iter.remaining
+        }
+    }
+}
+
+impl<'a, T> Iterator for InOrder<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a T> {
+        self.next_depth().map(|(data, _)| data)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for InOrder<'_, T> {}
+
+impl<T> FusedIterator for InOrder<'_, T> {}
+
+impl<T> Clone for InOrder<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self { stack: self.stack.clone(), remaining: self.remaining, marker: PhantomData }
+    }
+}
+
+impl<'a, T> Iterator for PreOrder<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a T> {
+        self.next_depth().map(|(data, _)| data)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for PreOrder<'_, T> {}
+
+impl<T> FusedIterator for PreOrder<'_, T> {}
+
+impl<T> Clone for PreOrder<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self { stack: self.stack.clone(), remaining: self.remaining, marker: PhantomData }
+    }
+}
+
+impl<'a, T> Iterator for PostOrder<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a T> {
+        self.next_depth().map(|(data, _)| data)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for PostOrder<'_, T> {}
+
+impl<T> FusedIterator for PostOrder<'_, T> {}
+
+impl<T> Clone for PostOrder<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self { stack: self.stack.clone(), remaining: self.remaining, marker: PhantomData }
+    }
+}
+
+impl<'a, T> Iterator for LevelOrder<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a T> {
+        self.next_depth().map(|(data, _)| data)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for LevelOrder<'_, T> {}
+
+impl<T> FusedIterator for LevelOrder<'_, T> {}
+
+impl<T> Clone for LevelOrder<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self { queue: self.queue.clone(), remaining: self.remaining, marker: PhantomData }
+    }
+}
+
+impl<'a, T> Iterator for Traversal<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a T> {
+        self.next_depth().map(|(data, _)| data)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining(), Some(self.remaining()))
+    }
+}
+
+impl<T> ExactSizeIterator for Traversal<'_, T> {}
+
+impl<T> FusedIterator for Traversal<'_, T> {}
+
+impl<T> Clone for Traversal<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        match self {
+            Self::InOrder(iter) => Self::InOrder(iter.clone()),
+            Self::PreOrder(iter) => Self::PreOrder(iter.clone()),
+            Self::PostOrder(iter) => Self::PostOrder(iter.clone())
+        }
+    }
+}
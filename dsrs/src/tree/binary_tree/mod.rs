@@ -0,0 +1,7 @@
+mod node;
+mod tree;
+mod iter;
+mod impls;
+
+pub use tree::{BinaryTree, Order};
+pub use iter::{InOrder, LevelOrder, PostOrder, PreOrder, Traversal};
@@ -0,0 +1,32 @@
+use mem::ptr::NonNull;
+
+pub(super) type Link<T> = Option<NonNull<Node<T>>>;
+
+pub(super) struct Node<T> {
+    pub data: T,
+    pub left: Link<T>,
+    pub right: Link<T>
+}
+
+impl<T> Node<T> {
+    /// Leaks a new node without children, so it can be linked.
+    #[inline]
+    pub fn leaf(data: T) -> NonNull<Self> {
+        let node = Box::new(Self { data, left: None, right: None });
+        // SAFETY: boxes are never null
+        unsafe { NonNull::new_unchecked(Box::into_raw(node)) }
+    }
+
+    /// Drops a node created by [`Node::leaf`], returning its contents.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have been created by [`Node::leaf`] and must not be used
+    /// after this.
+    #[inline]
+    pub unsafe fn free(ptr: NonNull<Self>) -> (T, Link<T>, Link<T>) {
+        // SAFETY: the caller guarantees this is a leaked box
+        let node = unsafe { Box::from_raw(ptr.as_ptr()) };
+        (node.data, node.left, node.right)
+    }
+}
@@ -0,0 +1,332 @@
+use super::iter::{InOrder, LevelOrder, PostOrder, PreOrder, Traversal};
+use super::node::{Link, Node};
+use crate::vec::{IntoIter, Vector};
+
+use mem::ptr::NonNull;
+
+/// Order of a traversal, like `Ordem` from Lab07.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Order {
+    /// Left subtree, then the root, then the right subtree.
+    InOrder,
+    /// The root, then the left subtree, then the right subtree.
+    PreOrder,
+    /// Left subtree, then the right subtree, then the root.
+    PostOrder
+}
+
+/// A binary tree with no particular order between its elements, like
+/// `Arvore` from Lab07.
+pub struct BinaryTree<T> {
+    pub(super) root: Link<T>,
+    pub(super) length: usize
+}
+
+/// Builds a balanced subtree with the next `len` items, taking them in the
+/// given `order`, like `_constroi_rec`.
+///
+/// The recursion only goes as deep as the height of the subtree.
+fn build<T>(items: &mut IntoIter<T>, len: usize, order: Order) -> Link<T> {
+    if len == 0 {
+        return None
+    }
+    let (left_len, right_len) = ((len - 1) / 2, len / 2);
+    let (data, left, right) = match order {
+        Order::PreOrder => {
+            let data = items.next().expect("there should be 'len' items left");
+            let left = build(items, left_len, order);
+            (data, left, build(items, right_len, order))
+        }
+        Order::InOrder => {
+            let left = build(items, left_len, order);
+            let data = items.next().expect("there should be 'len' items left");
+            (data, left, build(items, right_len, order))
+        }
+        Order::PostOrder => {
+            let left = build(items, left_len, order);
+            let right = build(items, right_len, order);
+            (items.next().expect("there should be 'len' items left"), left, right)
+        }
+    };
+    let node = Node::leaf(data);
+    // SAFETY: the node was just created
+    unsafe {
+        (*node.as_ptr()).left = left;
+        (*node.as_ptr()).right = right
+    }
+    Some(node)
+}
+
+impl<T> BinaryTree<T> {
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self { root: None, length: 0 }
+    }
+
+    /// Builds a balanced tree whose traversal in `order` yields `items`,
+    /// like `constroi_de_lista`.
+    #[must_use]
+    pub fn from_traversal<I: IntoIterator<Item = T>>(items: I, order: Order) -> Self {
+        let items: Vector<T> = items.into_iter().collect();
+        let length = items.len();
+        let root = build(&mut items.into_iter(), length, order);
+        Self { root, length }
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.length
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn root(&self) -> Option<&T> {
+        // SAFETY: the root is valid while the tree is borrowed
+        self.root.map(|ptr| unsafe { &(*ptr.as_ptr()).data })
+    }
+
+    /// Same as [`BinaryTree::in_order`].
+    #[must_use]
+    #[inline]
+    pub fn iter(&self) -> InOrder<'_, T> {
+        self.in_order()
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn in_order(&self) -> InOrder<'_, T> {
+        InOrder::new(self.root, self.length)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn pre_order(&self) -> PreOrder<'_, T> {
+        PreOrder::new(self.root, self.length)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn post_order(&self) -> PostOrder<'_, T> {
+        PostOrder::new(self.root, self.length)
+    }
+
+    /// Goes through each level, from the root down and from left to right.
+    #[must_use]
+    #[inline]
+    pub fn level_order(&self) -> LevelOrder<'_, T> {
+        LevelOrder::new(self.root, self.length)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn traverse(&self, order: Order) -> Traversal<'_, T> {
+        match order {
+            Order::InOrder => Traversal::InOrder(self.in_order()),
+            Order::PreOrder => Traversal::PreOrder(self.pre_order()),
+            Order::PostOrder => Traversal::PostOrder(self.post_order())
+        }
+    }
+
+    /// Number of levels in the tree, which is zero when it is empty.
+    #[must_use]
+    pub fn height(&self) -> usize {
+        let mut levels = self.level_order();
+        let mut height = 0;
+        while let Some((_, depth)) = levels.next_depth() {
+            height = depth + 1
+        }
+        height
+    }
+
+    /// Removes every element.
+    pub fn clear(&mut self) {
+        let mut stack = Vector::new();
+        stack.extend(self.root.take());
+        self.length = 0;
+
+        while let Some(ptr) = stack.pop() {
+            // SAFETY: each node is only reachable from its parent, which
+            // was already freed
+            let (_, left, right) = unsafe { Node::free(ptr) };
+            stack.extend(left);
+            stack.extend(right)
+        }
+    }
+}
+
+impl<T: PartialEq> BinaryTree<T> {
+    /// Searches every node, like `tem_elemento`.
+    #[must_use]
+    #[inline]
+    pub fn contains(&self, data: &T) -> bool {
+        self.pre_order().any(|item| item == data)
+    }
+
+    /// Distance from the root to the shallowest node with `data`.
+    #[must_use]
+    pub fn depth_of(&self, data: &T) -> Option<usize> {
+        let mut levels = self.level_order();
+        while let Some((item, depth)) = levels.next_depth() {
+            if item == data {
+                return Some(depth)
+            }
+        }
+        None
+    }
+
+    /// The elements that are also in `other`, in the given traversal
+    /// `order`, with their depth in this tree, like `interseccao`.
+    ///
+    /// Since neither tree is ordered and the elements are only
+    /// [`PartialEq`], all of `other` is searched for each element, so this
+    /// takes time proportional to the product of both lengths.
+    #[must_use]
+    pub fn intersection(&self, other: &Self, order: Order) -> Vector<(&T, usize)> {
+        let mut common = Vector::new();
+        let mut nodes = self.traverse(order);
+        while let Some((item, depth)) = nodes.next_depth() {
+            if other.contains(item) {
+                common.push((item, depth))
+            }
+        }
+        common
+    }
+}
+
+impl<T: Clone + PartialEq> BinaryTree<T> {
+    /// Rebuilds the only tree with these pre-order and in-order traversals.
+    ///
+    /// Returns `None` when no tree has both traversals. The elements should
+    /// be distinct, otherwise a matching tree might not be found.
+    #[must_use]
+    pub fn from_pre_in_order(pre_order: &[T], in_order: &[T]) -> Option<Self> {
+        if pre_order.len() != in_order.len() {
+            return None
+        }
+        let mut tree = Self::new();
+        // nodes whose right subtree is still open, from the root down
+        let mut stack: Vector<NonNull<Node<T>>> = Vector::new();
+        let mut next_in_order = 0;
+
+        for data in pre_order {
+            let mut parent = None;
+            while let Some(&ptr) = stack.last() {
+                // SAFETY: nodes in the stack are owned by the tree
+                let node: &Node<T> = unsafe { &*ptr.as_ptr() };
+                if in_order.get(next_in_order) != Some(&node.data) {
+                    break
+                }
+                parent = stack.pop();
+                next_in_order += 1
+            }
+
+            let node = Node::leaf(data.clone());
+            // SAFETY: the parent is owned by the tree and its link is empty,
+            // since each node gets at most one left and one right child
+            unsafe {
+                match (parent, stack.last()) {
+                    (Some(parent), _) => (*parent.as_ptr()).right = Some(node),
+                    (None, Some(top)) => (*top.as_ptr()).left = Some(node),
+                    (None, None) => tree.root = Some(node)
+                }
+            }
+            tree.length += 1;
+            stack.push(node)
+        }
+
+        let matches = tree.pre_order().eq(pre_order) && tree.in_order().eq(in_order);
+        if matches { Some(tree) } else { None }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BinaryTree, Order};
+
+    #[test]
+    fn from_each_order() {
+        // the same balanced shape, with 4 at the root
+        //        4
+        //      /   \
+        //     2     6
+        //    / \   / \
+        //   1   3 5   7
+        let tree = BinaryTree::from_traversal(1..=7, Order::InOrder);
+        assert_eq!(tree.root(), Some(&4));
+        assert!(tree.pre_order().eq(&[4, 2, 1, 3, 6, 5, 7]));
+        assert!(tree.post_order().eq(&[1, 3, 2, 5, 7, 6, 4]));
+        assert!(tree.level_order().eq(&[4, 2, 6, 1, 3, 5, 7]));
+
+        for &order in &[Order::InOrder, Order::PreOrder, Order::PostOrder] {
+            for len in 0..20 {
+                let tree = BinaryTree::from_traversal(0..len, order);
+                assert_eq!(tree.len(), len);
+                assert!(tree.traverse(order).eq(&(0..len).collect::<Vec<_>>()));
+                assert_eq!(tree.traverse(order).len(), len);
+                assert_eq!(tree.height(), 8 * std::mem::size_of::<usize>() - len.leading_zeros() as usize)
+            }
+        }
+        let tree: BinaryTree<&str> = BinaryTree::new();
+        assert_eq!((tree.height(), tree.root()), (0, None))
+    }
+
+    #[test]
+    fn reconstruction() {
+        let pre = ['F', 'B', 'A', 'D', 'C', 'E', 'G', 'I', 'H'];
+        let ino = ['A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I'];
+        let tree = BinaryTree::from_pre_in_order(&pre, &ino).unwrap();
+        assert!(tree.post_order().eq(&['A', 'C', 'E', 'D', 'B', 'H', 'I', 'G', 'F']));
+        assert!(tree.level_order().eq(&['F', 'B', 'G', 'A', 'D', 'I', 'C', 'E', 'H']));
+        assert_eq!(tree.height(), 4);
+        assert_eq!(tree.depth_of(&'H'), Some(3));
+        assert_eq!(tree.depth_of(&'Z'), None);
+
+        let copy = tree.clone();
+        assert!(copy.level_order().eq(tree.level_order()));
+        assert_eq!(format!("{:?}", copy), format!("{:?}", ino));
+
+        // degenerate trees, going only left or only right
+        let left = BinaryTree::from_pre_in_order(&[3, 2, 1], &[1, 2, 3]).unwrap();
+        let right = BinaryTree::from_pre_in_order(&[1, 2, 3], &[1, 2, 3]).unwrap();
+        assert_eq!((left.height(), right.height()), (3, 3));
+        assert!(BinaryTree::<u8>::from_pre_in_order(&[], &[]).unwrap().is_empty());
+
+        assert!(BinaryTree::from_pre_in_order(&[1, 2, 3], &[3, 1, 2]).is_none());
+        assert!(BinaryTree::from_pre_in_order(&[1, 2], &[1, 2, 3]).is_none());
+        assert!(BinaryTree::from_pre_in_order(&[1, 2, 4], &[1, 2, 3]).is_none())
+    }
+
+    #[test]
+    fn intersection() {
+        let a = BinaryTree::from_traversal(1..=7, Order::InOrder);
+        let b: BinaryTree<_> = vec![6, 9, 2, 4, 8].into_iter().collect();
+        assert!(b.contains(&9) && !b.contains(&1));
+
+        let common = a.intersection(&b, Order::InOrder);
+        assert_eq!(common.as_slice(), &[(&2, 1), (&4, 0), (&6, 1)]);
+        let common = a.intersection(&b, Order::PostOrder);
+        assert_eq!(common.as_slice(), &[(&2, 1), (&6, 1), (&4, 0)]);
+        assert!(a.intersection(&BinaryTree::new(), Order::PreOrder).is_empty())
+    }
+
+    #[test]
+    fn long_path() {
+        // a long path to the left, which recursion could not handle
+        let len = 100_000;
+        let pre: Vec<_> = (0..len).rev().collect();
+        let ino: Vec<_> = (0..len).collect();
+        let tree = BinaryTree::from_pre_in_order(&pre, &ino).unwrap();
+        assert_eq!(tree.height(), len);
+        assert_eq!(tree.depth_of(&0), Some(len - 1));
+        assert!(tree.post_order().eq(&ino));
+        assert!(tree.clone().level_order().eq(tree.level_order()))
+    }
+}
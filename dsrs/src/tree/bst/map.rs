@@ -0,0 +1,904 @@
+use super::node::{leftmost, rightmost, size, successor, Link, Node};
+use crate::vec::Vector;
+
+use mem::ptr::NonNull;
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::fmt::{Debug, Formatter, Result};
+use std::iter::{FromIterator, FusedIterator};
+use std::marker::PhantomData;
+use std::ops::{Bound, RangeBounds};
+
+/// Ordered map over an unbalanced binary search tree, like `ABB` from
+/// Lab08.
+///
+/// Operations take time proportional to the height of the tree, which
+/// depends on the insertion order. None of them are recursive, so even a
+/// tree shaped like a list does not overflow the stack.
+///
+/// Each node also knows the size of its subtree, for [`BstMap::rank`] and
+/// [`BstMap::select`].
+pub struct BstMap<K, V> {
+    root: Link<K, V>,
+    length: usize,
+    marker: PhantomData<Box<Node<K, V>>>
+}
+
+/// Borrows the entry of a node.
+///
+/// # Safety
+///
+/// `ptr` must be a node owned by a map borrowed for `'a`.
+#[inline]
+unsafe fn entry<'a, K, V>(ptr: NonNull<Node<K, V>>) -> (&'a K, &'a V) {
+    // SAFETY: guaranteed by the caller
+    unsafe { (&(*ptr.as_ptr()).key, &(*ptr.as_ptr()).value) }
+}
+
+impl<K, V> BstMap<K, V> {
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self { root: None, length: 0, marker: PhantomData }
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.length
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    #[inline]
+    fn first_node(&self) -> Link<K, V> {
+        // SAFETY: the root is a valid node owned by the map
+        self.root.map(|root| unsafe { leftmost(root) })
+    }
+
+    #[inline]
+    fn last_node(&self) -> Link<K, V> {
+        // SAFETY: the root is a valid node owned by the map
+        self.root.map(|root| unsafe { rightmost(root) })
+    }
+
+    /// Removes a node, replacing it with its successor when it has two
+    /// children, like `remove_elemento`.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be a node owned by the map.
+    unsafe fn unlink(&mut self, ptr: NonNull<Node<K, V>>) -> (K, V) {
+        // SAFETY: every node reached here is owned by the map, which is
+        // mutably borrowed
+        unsafe {
+            let mut target = ptr;
+            if let (Some(_), Some(right)) = ((*ptr.as_ptr()).left, (*ptr.as_ptr()).right) {
+                // the successor has no left child, so it is easier to remove
+                let next = leftmost(right);
+                std::mem::swap(&mut (*ptr.as_ptr()).key, &mut (*next.as_ptr()).key);
+                std::mem::swap(&mut (*ptr.as_ptr()).value, &mut (*next.as_ptr()).value);
+                target = next
+            }
+
+            let child = (*target.as_ptr()).left.or((*target.as_ptr()).right);
+            let parent = (*target.as_ptr()).parent;
+            if let Some(child) = child {
+                (*child.as_ptr()).parent = parent
+            }
+            match parent {
+                None => self.root = child,
+                Some(parent) if (*parent.as_ptr()).left == Some(target) => (*parent.as_ptr()).left = child,
+                Some(parent) => (*parent.as_ptr()).right = child
+            }
+
+            let mut ancestor = parent;
+            while let Some(ptr) = ancestor {
+                (*ptr.as_ptr()).size -= 1;
+                ancestor = (*ptr.as_ptr()).parent
+            }
+            self.length -= 1;
+            Node::free(target)
+        }
+    }
+
+    /// Drops every entry, without any extra memory.
+    pub fn clear(&mut self) {
+        let mut link = self.root.take();
+        self.length = 0;
+        while let Some(ptr) = link {
+            // SAFETY: 'ptr' is a valid node owned by the map and it is only
+            // freed after its children
+            unsafe {
+                let node = ptr.as_ptr();
+                if let Some(left) = (*node).left.take() {
+                    link = Some(left)
+                } else if let Some(right) = (*node).right.take() {
+                    link = Some(right)
+                } else {
+                    link = (*node).parent;
+                    Node::free(ptr);
+                }
+            }
+        }
+    }
+
+    /// The entry with the smallest key.
+    #[must_use]
+    #[inline]
+    pub fn min(&self) -> Option<(&K, &V)> {
+        // SAFETY: the node is owned by the map
+        self.first_node().map(|ptr| unsafe { entry(ptr) })
+    }
+
+    /// The entry with the greatest key.
+    #[must_use]
+    #[inline]
+    pub fn max(&self) -> Option<(&K, &V)> {
+        // SAFETY: the node is owned by the map
+        self.last_node().map(|ptr| unsafe { entry(ptr) })
+    }
+
+    #[inline]
+    pub fn pop_min(&mut self) -> Option<(K, V)> {
+        let ptr = self.first_node()?;
+        // SAFETY: 'ptr' is a node owned by the map
+        Some(unsafe { self.unlink(ptr) })
+    }
+
+    #[inline]
+    pub fn pop_max(&mut self) -> Option<(K, V)> {
+        let ptr = self.last_node()?;
+        // SAFETY: 'ptr' is a node owned by the map
+        Some(unsafe { self.unlink(ptr) })
+    }
+
+    /// The entry with the `index`-th smallest key, counting from zero.
+    #[must_use]
+    pub fn select(&self, mut index: usize) -> Option<(&K, &V)> {
+        let mut link = self.root;
+        while let Some(ptr) = link {
+            // SAFETY: 'ptr' is a valid node owned by the map
+            let node = unsafe { &*ptr.as_ptr() };
+            // SAFETY: same as above, for its child
+            let before = unsafe { size(node.left) };
+            link = match index.cmp(&before) {
+                Ordering::Less => node.left,
+                Ordering::Equal => return Some((&node.key, &node.value)),
+                Ordering::Greater => {
+                    index -= before + 1;
+                    node.right
+                }
+            }
+        }
+        None
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter::new(self.first_node(), self.length)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut::new(self.first_node(), self.length)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys(self.iter())
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values(self.iter())
+    }
+}
+
+impl<K: Ord, V> BstMap<K, V> {
+    /// Node with exactly the given key.
+    #[inline]
+    fn find<Q>(&self, key: &Q) -> Link<K, V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized
+    {
+        let mut link = self.root;
+        while let Some(ptr) = link {
+            // SAFETY: 'ptr' is a valid node owned by the map
+            let node = unsafe { &*ptr.as_ptr() };
+            link = match key.cmp(node.key.borrow()) {
+                Ordering::Less => node.left,
+                Ordering::Equal => return Some(ptr),
+                Ordering::Greater => node.right
+            }
+        }
+        None
+    }
+
+    /// First node whose key is not `before` the searched one.
+    #[inline]
+    fn seek<F: FnMut(&K) -> bool>(&self, mut before: F) -> Link<K, V> {
+        let (mut link, mut found) = (self.root, None);
+        while let Some(ptr) = link {
+            // SAFETY: 'ptr' is a valid node owned by the map
+            let node = unsafe { &*ptr.as_ptr() };
+            if before(&node.key) {
+                link = node.right
+            } else {
+                found = Some(ptr);
+                link = node.left
+            }
+        }
+        found
+    }
+
+    /// Last node whose key is `before` the searched one.
+    #[inline]
+    fn seek_back<F: FnMut(&K) -> bool>(&self, mut before: F) -> Link<K, V> {
+        let (mut link, mut found) = (self.root, None);
+        while let Some(ptr) = link {
+            // SAFETY: 'ptr' is a valid node owned by the map
+            let node = unsafe { &*ptr.as_ptr() };
+            if before(&node.key) {
+                found = Some(ptr);
+                link = node.right
+            } else {
+                link = node.left
+            }
+        }
+        found
+    }
+
+    /// Inserts the entry as a new leaf, like `insere_elemento`, returning
+    /// the old value when the key was already present. In that case, the
+    /// old key is kept.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let (mut link, mut parent) = (self.root, None);
+        let mut on_left = false;
+        while let Some(ptr) = link {
+            // SAFETY: 'ptr' is a valid node owned by the map, which is
+            // mutably borrowed
+            let node = unsafe { &mut *ptr.as_ptr() };
+            match key.cmp(&node.key) {
+                Ordering::Less => on_left = true,
+                Ordering::Equal => return Some(std::mem::replace(&mut node.value, value)),
+                Ordering::Greater => on_left = false
+            }
+            link = if on_left { node.left } else { node.right };
+            parent = Some(ptr)
+        }
+
+        let ptr = Node::leaf(key, value, parent);
+        // SAFETY: the ancestors are valid nodes owned by the map
+        unsafe {
+            match parent {
+                None => self.root = Some(ptr),
+                Some(parent) if on_left => (*parent.as_ptr()).left = Some(ptr),
+                Some(parent) => (*parent.as_ptr()).right = Some(ptr)
+            }
+            let mut ancestor = parent;
+            while let Some(ptr) = ancestor {
+                (*ptr.as_ptr()).size += 1;
+                ancestor = (*ptr.as_ptr()).parent
+            }
+        }
+        self.length += 1;
+        None
+    }
+
+    #[inline]
+    pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized
+    {
+        let ptr = self.find(key)?;
+        // SAFETY: 'ptr' is a node owned by the map
+        Some(unsafe { self.unlink(ptr) })
+    }
+
+    #[inline]
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized
+    {
+        self.remove_entry(key).map(|(_, value)| value)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized
+    {
+        self.get_key_value(key).map(|(_, value)| value)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized
+    {
+        // SAFETY: the node is owned by the map
+        self.find(key).map(|ptr| unsafe { entry(ptr) })
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized
+    {
+        let ptr = self.find(key)?;
+        // SAFETY: 'ptr' is a valid node owned by the map, which is
+        // mutably borrowed
+        Some(unsafe { &mut (*ptr.as_ptr()).value })
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized
+    {
+        self.find(key).is_some()
+    }
+
+    /// The entry with the greatest key less than or equal to `key`.
+    #[must_use]
+    #[inline]
+    pub fn floor<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized
+    {
+        // SAFETY: the node is owned by the map
+        self.seek_back(|k| k.borrow() <= key).map(|ptr| unsafe { entry(ptr) })
+    }
+
+    /// The entry with the smallest key greater than or equal to `key`.
+    #[must_use]
+    #[inline]
+    pub fn ceiling<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized
+    {
+        // SAFETY: the node is owned by the map
+        self.seek(|k| k.borrow() < key).map(|ptr| unsafe { entry(ptr) })
+    }
+
+    /// Number of keys smaller than `key`, which is the index it has or
+    /// would have in [`BstMap::select`].
+    #[must_use]
+    pub fn rank<Q>(&self, key: &Q) -> usize
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized
+    {
+        let (mut link, mut rank) = (self.root, 0);
+        while let Some(ptr) = link {
+            // SAFETY: 'ptr' is a valid node owned by the map
+            let node = unsafe { &*ptr.as_ptr() };
+            if node.key.borrow() < key {
+                // SAFETY: same as above, for its child
+                rank += unsafe { size(node.left) } + 1;
+                link = node.right
+            } else {
+                link = node.left
+            }
+        }
+        rank
+    }
+
+    /// Iterates over the entries with keys inside `range`, in order, like
+    /// `imprime_intervalo`.
+    ///
+    /// The iterator is empty if the range starts after its end.
+    #[must_use]
+    #[inline]
+    pub fn range<Q, R>(&self, range: R) -> Range<'_, K, V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+        R: RangeBounds<Q>
+    {
+        let start = match range.start_bound() {
+            Bound::Included(start) => self.seek(|k| k.borrow() < start),
+            Bound::Excluded(start) => self.seek(|k| k.borrow() <= start),
+            Bound::Unbounded => self.first_node()
+        };
+        let end = match range.end_bound() {
+            Bound::Included(end) => self.seek(|k| k.borrow() <= end),
+            Bound::Excluded(end) => self.seek(|k| k.borrow() < end),
+            Bound::Unbounded => None
+        };
+
+        if let (Some(first), Some(last)) = (start, end) {
+            // SAFETY: both are valid nodes owned by the map
+            if unsafe { (*first.as_ptr()).key > (*last.as_ptr()).key } {
+                return Range::new(end, end)
+            }
+        }
+        Range::new(start, end)
+    }
+}
+
+impl<K, V> Drop for BstMap<K, V> {
+    #[inline]
+    fn drop(&mut self) {
+        self.clear()
+    }
+}
+
+impl<K, V> Default for BstMap<K, V> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Debug, V: Debug> Debug for BstMap<K, V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: Clone, V: Clone> Clone for BstMap<K, V> {
+    /// Copies each node in pre-order, keeping the same shape.
+    fn clone(&self) -> Self {
+        let mut map = Self::new();
+        map.length = self.length;
+        // each node to copy, with the parent of its copy and on which side
+        let mut stack = Vector::new();
+        stack.extend(self.root.map(|root| (root, None, false)));
+
+        while let Some((ptr, parent, on_left)) = stack.pop() {
+            // SAFETY: 'ptr' is owned by 'self' and 'parent' is a copy already
+            // linked in the new map
+            unsafe {
+                let node: &Node<K, V> = &*ptr.as_ptr();
+                let copy = Node::leaf(node.key.clone(), node.value.clone(), parent);
+                (*copy.as_ptr()).size = node.size;
+                match parent {
+                    None => map.root = Some(copy),
+                    Some(parent) if on_left => (*parent.as_ptr()).left = Some(copy),
+                    Some(parent) => (*parent.as_ptr()).right = Some(copy)
+                }
+                stack.extend(node.right.map(|right| (right, Some(copy), false)));
+                stack.extend(node.left.map(|left| (left, Some(copy), true)))
+            }
+        }
+        map
+    }
+}
+
+impl<K: PartialEq, V: PartialEq> PartialEq for BstMap<K, V> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<K: Eq, V: Eq> Eq for BstMap<K, V> {}
+
+impl<K: Ord, V> FromIterator<(K, V)> for BstMap<K, V> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K: Ord, V> Extend<(K, V)> for BstMap<K, V> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+// SAFETY: the nodes and their parent links are only reachable from the
+// root of the map, so sending it moves every entry, needing both 'Send'
+unsafe impl<K: Send, V: Send> Send for BstMap<K, V> {}
+// SAFETY: searches through '&BstMap' only read the nodes and give out
+// '&K' and '&V', which can be shared when both are 'Sync'
+unsafe impl<K: Sync, V: Sync> Sync for BstMap<K, V> {}
+
+pub struct Iter<'a, K, V> {
+    next: Link<K, V>,
+    remaining: usize,
+    marker: PhantomData<&'a Node<K, V>>
+}
+
+impl<K, V> Iter<'_, K, V> {
+    #[inline]
+    const fn new(first: Link<K, V>, length: usize) -> Self {
+        Self { next: first, remaining: length, marker: PhantomData }
+    }
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<(&'a K, &'a V)> {
+        self.next.map(|ptr| {
+            // SAFETY: 'ptr' is a valid node of a borrowed map
+            let node = unsafe { &*ptr.as_ptr() };
+            // SAFETY: same as above
+            self.next = unsafe { successor(ptr) };
+            self.remaining -= 1;
+            (&node.key, &node.value)
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
+
+impl<K, V> FusedIterator for Iter<'_, K, V> {}
+
+impl<K, V> Clone for Iter<'_, K, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.next, self.remaining)
+    }
+}
+
+pub struct IterMut<'a, K, V> {
+    next: Link<K, V>,
+    remaining: usize,
+    marker: PhantomData<&'a mut Node<K, V>>
+}
+
+impl<K, V> IterMut<'_, K, V> {
+    #[inline]
+    const fn new(first: Link<K, V>, length: usize) -> Self {
+        Self { next: first, remaining: length, marker: PhantomData }
+    }
+}
+
+impl<'a, K, V> Iterator for IterMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    #[inline]
+    fn next(&mut self) -> Option<(&'a K, &'a mut V)> {
+        self.next.map(|ptr| {
+            // SAFETY: 'ptr' is a valid node of a mutably borrowed map,
+            // visited only once, and only its links are read after this
+            unsafe {
+                self.next = successor(ptr);
+                self.remaining -= 1;
+                (&(*ptr.as_ptr()).key, &mut (*ptr.as_ptr()).value)
+            }
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K, V> ExactSizeIterator for IterMut<'_, K, V> {}
+
+impl<K, V> FusedIterator for IterMut<'_, K, V> {}
+
+pub struct Keys<'a, K, V>(Iter<'a, K, V>);
+
+impl<'a, K, V> Iterator for Keys<'a, K, V> {
+    type Item = &'a K;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a K> {
+        self.0.next().map(|(key, _)| key)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<K, V> ExactSizeIterator for Keys<'_, K, V> {}
+
+impl<K, V> FusedIterator for Keys<'_, K, V> {}
+
+impl<K, V> Clone for Keys<'_, K, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+pub struct Values<'a, K, V>(Iter<'a, K, V>);
+
+impl<'a, K, V> Iterator for Values<'a, K, V> {
+    type Item = &'a V;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a V> {
+        self.0.next().map(|(_, value)| value)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<K, V> ExactSizeIterator for Values<'_, K, V> {}
+
+impl<K, V> FusedIterator for Values<'_, K, V> {}
+
+impl<K, V> Clone for Values<'_, K, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+/// Entries from a node up to, but not including, another one.
+pub struct Range<'a, K, V> {
+    next: Link<K, V>,
+    end: Link<K, V>,
+    marker: PhantomData<&'a Node<K, V>>
+}
+
+impl<K, V> Range<'_, K, V> {
+    #[inline]
+    const fn new(start: Link<K, V>, end: Link<K, V>) -> Self {
+        Self { next: start, end, marker: PhantomData }
+    }
+}
+
+impl<'a, K, V> Iterator for Range<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<(&'a K, &'a V)> {
+        if self.next == self.end {
+            return None
+        }
+        self.next.map(|ptr| {
+            // SAFETY: 'ptr' is a valid node of a borrowed map
+            let node = unsafe { &*ptr.as_ptr() };
+            // SAFETY: same as above
+            self.next = unsafe { successor(ptr) };
+            (&node.key, &node.value)
+        })
+    }
+}
+
+impl<K, V> FusedIterator for Range<'_, K, V> {}
+
+impl<K, V> Clone for Range<'_, K, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.next, self.end)
+    }
+}
+
+pub struct IntoIter<K, V> {
+    map: BstMap<K, V>
+}
+
+impl<K, V> Iterator for IntoIter<K, V> {
+    type Item = (K, V);
+
+    #[inline]
+    fn next(&mut self) -> Option<(K, V)> {
+        self.map.pop_min()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.map.len(), Some(self.map.len()))
+    }
+}
+
+impl<K, V> ExactSizeIterator for IntoIter<K, V> {}
+
+impl<K, V> FusedIterator for IntoIter<K, V> {}
+
+impl<K, V> IntoIterator for BstMap<K, V> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V>;
+
+    #[inline]
+    fn into_iter(self) -> IntoIter<K, V> {
+        IntoIter { map: self }
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a BstMap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a mut BstMap<K, V> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> IterMut<'a, K, V> {
+        self.iter_mut()
+    }
+}
+
+// SAFETY: 'Iter' climbs the parent links of a borrowed tree, giving out
+// '&K' and '&V', so another thread may hold it when both are 'Sync'
+unsafe impl<K: Sync, V: Sync> Send for Iter<'_, K, V> {}
+// SAFETY: a shared 'Iter' can only be cloned, giving out the same entries
+unsafe impl<K: Sync, V: Sync> Sync for Iter<'_, K, V> {}
+// SAFETY: 'IterMut' gives out '&K' with a unique '&mut V' for each node
+// once, so it can move when 'K: Sync' and 'V: Send'
+unsafe impl<K: Sync, V: Send> Send for IterMut<'_, K, V> {}
+// SAFETY: a shared 'IterMut' can't advance, so like '&mut V' it only
+// needs both to be 'Sync'
+unsafe impl<K: Sync, V: Sync> Sync for IterMut<'_, K, V> {}
+// SAFETY: 'Range' walks successors of a borrowed tree up to 'end', giving
+// out '&K' and '&V', which needs both to be 'Sync'
+unsafe impl<K: Sync, V: Sync> Send for Range<'_, K, V> {}
+// SAFETY: a shared 'Range' can only be cloned, giving out the same entries
+unsafe impl<K: Sync, V: Sync> Sync for Range<'_, K, V> {}
+
+#[cfg(test)]
+mod tests {
+    use super::BstMap;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn ordered_entries() {
+        let mut map = BstMap::new();
+        let mut expected = BTreeMap::new();
+        for i in 0..500_u32 {
+            let key = (i * 7919) % 1009;
+            assert_eq!(map.insert(key, i), expected.insert(key, i))
+        }
+        for i in 0..300_u32 {
+            let key = (i * 31) % 1009;
+            assert_eq!(map.remove(&key), expected.remove(&key))
+        }
+        assert_eq!(map.len(), expected.len());
+        assert!(map.iter().eq(expected.iter()));
+        assert_eq!(map.min(), expected.iter().next());
+        assert_eq!(map.max(), expected.iter().next_back());
+
+        if let Some(value) = map.get_mut(&998) {
+            *value = 0
+        }
+        assert_eq!(map.get(&998), Some(&0));
+        assert!(!map.contains_key(&31));
+        assert_eq!(map.keys().len(), map.len());
+        assert_eq!(map.values().count(), map.len())
+    }
+
+    #[test]
+    fn successor_replacement() {
+        //       50
+        //     /    \
+        //   30      70
+        //  /  \    /  \
+        // 20  40  60  80
+        //           \
+        //           65
+        let mut map: BstMap<_, _> = [50, 30, 70, 20, 40, 60, 80, 65].iter().map(|&k| (k, ())).collect();
+        assert_eq!(map.remove_entry(&50), Some((50, ())));
+        // 60 took the place of the root and 65 the place of 60
+        // SAFETY: the root is owned by the map
+        assert_eq!(map.root.map(|root| unsafe { (*root.as_ptr()).key }), Some(60));
+        assert!(map.keys().eq(&[20, 30, 40, 60, 65, 70, 80]));
+        assert_eq!(map.remove(&20), Some(()));
+        assert_eq!(map.remove(&30), Some(()));
+        assert_eq!(map.remove(&30), None);
+        assert!(map.keys().eq(&[40, 60, 65, 70, 80]));
+        assert_eq!(map.select(2), Some((&65, &())))
+    }
+
+    #[test]
+    fn order_statistics() {
+        let map: BstMap<_, _> = (0..20).map(|i| ((i * 7) % 20 * 5, i)).collect();
+        assert_eq!(map.floor(&42).map(|(k, _)| *k), Some(40));
+        assert_eq!(map.floor(&40).map(|(k, _)| *k), Some(40));
+        assert_eq!(map.floor(&-1), None);
+        assert_eq!(map.ceiling(&42).map(|(k, _)| *k), Some(45));
+        assert_eq!(map.ceiling(&96), None);
+
+        for (index, (key, _)) in map.iter().enumerate() {
+            assert_eq!(map.rank(key), index);
+            assert_eq!(map.select(index).map(|(k, _)| k), Some(key))
+        }
+        assert_eq!(map.rank(&42), 9);
+        assert_eq!(map.rank(&1000), 20);
+        assert_eq!(map.select(20), None)
+    }
+
+    #[test]
+    fn range_queries() {
+        use std::ops::Bound::{Excluded, Included};
+
+        let map: BstMap<_, _> = (0..20).map(|i| ((i * 7) % 20 * 5, i)).collect();
+        let keys = |range: super::Range<'_, i32, i32>| range.map(|(&k, _)| k).collect::<Vec<_>>();
+
+        // 'imprime_intervalo' goes from 'inicio' up to, but not including, 'fim'
+        assert_eq!(keys(map.range(10..25)), vec![10, 15, 20]);
+        assert_eq!(keys(map.range(11..=25)), vec![15, 20, 25]);
+        assert_eq!(keys(map.range(..12)), vec![0, 5, 10]);
+        assert_eq!(keys(map.range(88..)), vec![90, 95]);
+        assert_eq!(keys(map.range(41..44)), vec![]);
+        assert_eq!(keys(map.range((Included(50), Excluded(20)))), vec![]);
+        assert_eq!(map.range(..).count(), 20);
+        assert_eq!(keys(map.range((Excluded(10), Excluded(20)))), vec![15])
+    }
+
+    #[test]
+    fn ends_and_owning_iteration() {
+        let mut map: BstMap<_, _> = vec![(3, 'c'), (1, 'a'), (4, 'd'), (2, 'b')].into_iter().collect();
+        assert_eq!(map.pop_min(), Some((1, 'a')));
+        assert_eq!(map.pop_max(), Some((4, 'd')));
+        assert_eq!(map.len(), 2);
+
+        for (_, value) in &mut map {
+            *value = value.to_ascii_uppercase()
+        }
+        let copy = map.clone();
+        assert_eq!(copy, map);
+        assert_eq!(format!("{:?}", map), "{2: 'B', 3: 'C'}");
+
+        let mut iter = map.into_iter();
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.next(), Some((2, 'B')));
+        assert_eq!(iter.collect::<Vec<_>>(), vec![(3, 'C')]);
+
+        let mut empty: BstMap<i32, i32> = BstMap::new();
+        assert_eq!(empty.pop_max(), None);
+        assert_eq!(empty.remove(&0), None);
+        assert_eq!(empty.range(1..).next(), None)
+    }
+
+    #[test]
+    fn long_list_shape() {
+        // sorted insertions make every node a right child
+        let len = 10_000;
+        let mut map: BstMap<_, _> = (0..len).map(|i| (i, i)).collect();
+        assert_eq!(map.rank(&(len - 1)), len - 1);
+        assert_eq!(map.select(len / 2), Some((&(len / 2), &(len / 2))));
+        assert_eq!(map.floor(&len), Some((&(len - 1), &(len - 1))));
+        assert!(map.range(10..).map(|(&k, _)| k).eq(10..len));
+
+        let copy = map.clone();
+        for i in (0..len).step_by(2) {
+            assert_eq!(map.remove(&i), Some(i))
+        }
+        assert!(map.keys().copied().eq((1..len).step_by(2)));
+        assert_eq!(copy.len(), len)
+    }
+}
@@ -0,0 +1,6 @@
+mod node;
+pub mod map;
+pub mod set;
+
+pub use map::BstMap;
+pub use set::BstSet;
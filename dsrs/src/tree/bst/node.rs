@@ -0,0 +1,99 @@
+use mem::ptr::NonNull;
+
+pub(super) type Link<K, V> = Option<NonNull<Node<K, V>>>;
+
+pub(super) struct Node<K, V> {
+    pub key: K,
+    pub value: V,
+    pub parent: Link<K, V>,
+    pub left: Link<K, V>,
+    pub right: Link<K, V>,
+    /// Number of nodes in this subtree, including itself.
+    pub size: usize
+}
+
+impl<K, V> Node<K, V> {
+    /// Leaks a new node without children, so it can be linked.
+    #[inline]
+    pub fn leaf(key: K, value: V, parent: Link<K, V>) -> NonNull<Self> {
+        let node = Box::new(Self { key, value, parent, left: None, right: None, size: 1 });
+        // SAFETY: boxes are never null
+        unsafe { NonNull::new_unchecked(Box::into_raw(node)) }
+    }
+
+    /// Drops a node created by [`Node::leaf`], returning its entry.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have been created by [`Node::leaf`] and must not be used
+    /// after this.
+    #[inline]
+    pub unsafe fn free(ptr: NonNull<Self>) -> (K, V) {
+        // SAFETY: the caller guarantees this is a leaked box
+        let node = unsafe { Box::from_raw(ptr.as_ptr()) };
+        (node.key, node.value)
+    }
+}
+
+/// Number of nodes under `link`.
+///
+/// # Safety
+///
+/// `link` must be empty or point to a valid node.
+#[inline]
+pub(super) unsafe fn size<K, V>(link: Link<K, V>) -> usize {
+    // SAFETY: guaranteed by the caller
+    link.map_or(0, |ptr| unsafe { (*ptr.as_ptr()).size })
+}
+
+/// Node with the smallest key in the subtree of `ptr`.
+///
+/// # Safety
+///
+/// `ptr` must be a valid node, with valid descendants.
+#[inline]
+pub(super) unsafe fn leftmost<K, V>(mut ptr: NonNull<Node<K, V>>) -> NonNull<Node<K, V>> {
+    // SAFETY: guaranteed by the caller
+    while let Some(left) = unsafe { (*ptr.as_ptr()).left } {
+        ptr = left
+    }
+    ptr
+}
+
+/// Node with the greatest key in the subtree of `ptr`.
+///
+/// # Safety
+///
+/// `ptr` must be a valid node, with valid descendants.
+#[inline]
+pub(super) unsafe fn rightmost<K, V>(mut ptr: NonNull<Node<K, V>>) -> NonNull<Node<K, V>> {
+    // SAFETY: guaranteed by the caller
+    while let Some(right) = unsafe { (*ptr.as_ptr()).right } {
+        ptr = right
+    }
+    ptr
+}
+
+/// Node with the next key in the whole tree.
+///
+/// # Safety
+///
+/// `ptr` must be a valid node in a tree of valid nodes.
+#[inline]
+pub(super) unsafe fn successor<K, V>(ptr: NonNull<Node<K, V>>) -> Link<K, V> {
+    // SAFETY: guaranteed by the caller
+    unsafe {
+        if let Some(right) = (*ptr.as_ptr()).right {
+            return Some(leftmost(right))
+        }
+        // the first ancestor where we come from the left
+        let mut child = ptr;
+        while let Some(parent) = (*child.as_ptr()).parent {
+            if (*parent.as_ptr()).left == Some(child) {
+                return Some(parent)
+            }
+            child = parent
+        }
+        None
+    }
+}
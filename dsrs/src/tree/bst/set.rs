@@ -0,0 +1,349 @@
+use super::map::{self, BstMap};
+
+use std::borrow::Borrow;
+use std::fmt::{Debug, Formatter, Result};
+use std::iter::{FromIterator, FusedIterator};
+use std::ops::RangeBounds;
+
+/// Ordered set over a [`BstMap`] without values.
+pub struct BstSet<K> {
+    map: BstMap<K, ()>
+}
+
+impl<K> BstSet<K> {
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self { map: BstMap::new() }
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.map.clear()
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn min(&self) -> Option<&K> {
+        self.map.min().map(|(key, ())| key)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn max(&self) -> Option<&K> {
+        self.map.max().map(|(key, ())| key)
+    }
+
+    #[inline]
+    pub fn pop_min(&mut self) -> Option<K> {
+        self.map.pop_min().map(|(key, ())| key)
+    }
+
+    #[inline]
+    pub fn pop_max(&mut self) -> Option<K> {
+        self.map.pop_max().map(|(key, ())| key)
+    }
+
+    /// The `index`-th smallest key, counting from zero.
+    #[must_use]
+    #[inline]
+    pub fn select(&self, index: usize) -> Option<&K> {
+        self.map.select(index).map(|(key, ())| key)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, K> {
+        Iter(self.map.keys())
+    }
+}
+
+impl<K: Ord> BstSet<K> {
+    /// Returns `false` if the key was already present.
+    #[inline]
+    pub fn insert(&mut self, key: K) -> bool {
+        self.map.insert(key, ()).is_none()
+    }
+
+    /// Returns `false` if the key was not present.
+    #[inline]
+    pub fn remove<Q>(&mut self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized
+    {
+        self.map.remove(key).is_some()
+    }
+
+    #[inline]
+    pub fn take<Q>(&mut self, key: &Q) -> Option<K>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized
+    {
+        self.map.remove_entry(key).map(|(key, ())| key)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn contains<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized
+    {
+        self.map.contains_key(key)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn get<Q>(&self, key: &Q) -> Option<&K>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized
+    {
+        self.map.get_key_value(key).map(|(key, ())| key)
+    }
+
+    /// The greatest key less than or equal to `key`.
+    #[must_use]
+    #[inline]
+    pub fn floor<Q>(&self, key: &Q) -> Option<&K>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized
+    {
+        self.map.floor(key).map(|(key, ())| key)
+    }
+
+    /// The smallest key greater than or equal to `key`.
+    #[must_use]
+    #[inline]
+    pub fn ceiling<Q>(&self, key: &Q) -> Option<&K>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized
+    {
+        self.map.ceiling(key).map(|(key, ())| key)
+    }
+
+    /// Number of keys smaller than `key`.
+    #[must_use]
+    #[inline]
+    pub fn rank<Q>(&self, key: &Q) -> usize
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized
+    {
+        self.map.rank(key)
+    }
+
+    /// Iterates over the keys inside `range`, in order.
+    ///
+    /// The iterator is empty if the range starts after its end.
+    #[must_use]
+    #[inline]
+    pub fn range<Q, R>(&self, range: R) -> Range<'_, K>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+        R: RangeBounds<Q>
+    {
+        Range(self.map.range(range))
+    }
+}
+
+impl<K> Default for BstSet<K> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Debug> Debug for BstSet<K> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<K: Clone> Clone for BstSet<K> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self { map: self.map.clone() }
+    }
+}
+
+impl<K: PartialEq> PartialEq for BstSet<K> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.map == other.map
+    }
+}
+
+impl<K: Eq> Eq for BstSet<K> {}
+
+impl<K: Ord> FromIterator<K> for BstSet<K> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = K>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<K: Ord> Extend<K> for BstSet<K> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = K>>(&mut self, iter: I) {
+        self.map.extend(iter.into_iter().map(|key| (key, ())))
+    }
+}
+
+impl<'a, K: 'a + Ord + Copy> Extend<&'a K> for BstSet<K> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a K>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied())
+    }
+}
+
+pub struct Iter<'a, K>(map::Keys<'a, K, ()>);
+
+impl<'a, K> Iterator for Iter<'a, K> {
+    type Item = &'a K;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a K> {
+        self.0.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<K> ExactSizeIterator for Iter<'_, K> {}
+
+impl<K> FusedIterator for Iter<'_, K> {}
+
+impl<K> Clone for Iter<'_, K> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+pub struct Range<'a, K>(map::Range<'a, K, ()>);
+
+impl<'a, K> Iterator for Range<'a, K> {
+    type Item = &'a K;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a K> {
+        self.0.next().map(|(key, ())| key)
+    }
+}
+
+impl<K> FusedIterator for Range<'_, K> {}
+
+impl<K> Clone for Range<'_, K> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+pub struct IntoIter<K>(map::IntoIter<K, ()>);
+
+impl<K> Iterator for IntoIter<K> {
+    type Item = K;
+
+    #[inline]
+    fn next(&mut self) -> Option<K> {
+        self.0.next().map(|(key, ())| key)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<K> ExactSizeIterator for IntoIter<K> {}
+
+impl<K> FusedIterator for IntoIter<K> {}
+
+impl<K> IntoIterator for BstSet<K> {
+    type Item = K;
+    type IntoIter = IntoIter<K>;
+
+    #[inline]
+    fn into_iter(self) -> IntoIter<K> {
+        IntoIter(self.map.into_iter())
+    }
+}
+
+impl<'a, K> IntoIterator for &'a BstSet<K> {
+    type Item = &'a K;
+    type IntoIter = Iter<'a, K>;
+
+    #[inline]
+    fn into_iter(self) -> Iter<'a, K> {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BstSet;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn against_btree() {
+        let mut set = BstSet::new();
+        let mut expected = BTreeSet::new();
+        for i in 0..2000_u64 {
+            let key = (i * i + 7 * i) % 613;
+            if i % 3 == 0 {
+                assert_eq!(set.remove(&key), expected.remove(&key))
+            } else {
+                assert_eq!(set.insert(key), expected.insert(key))
+            }
+        }
+        assert_eq!(set.len(), expected.len());
+        assert!(set.iter().eq(expected.iter()));
+        assert!(set.range(100..200).eq(expected.range(100..200)));
+        assert_eq!(set.min(), expected.iter().next());
+        assert_eq!(set.max(), expected.iter().next_back());
+        assert_eq!(set.floor(&300), expected.range(..=300).next_back());
+        assert_eq!(set.ceiling(&300), expected.range(300..).next());
+        assert_eq!(set.rank(&300), expected.range(..300).count());
+        assert_eq!(set.select(set.rank(&300)), expected.range(300..).next())
+    }
+
+    #[test]
+    fn set_operations() {
+        let mut set: BstSet<_> = vec![5, 1, 4, 1, 3].into_iter().collect();
+        set.extend(&[2, 5]);
+        assert_eq!(format!("{:?}", set), "{1, 2, 3, 4, 5}");
+        assert!(set.contains(&4));
+        assert_eq!(set.take(&4), Some(4));
+        assert!(!set.contains(&4));
+        assert_eq!(set.get(&3), Some(&3));
+
+        assert_eq!(set.pop_min(), Some(1));
+        assert_eq!(set.pop_max(), Some(5));
+        assert_eq!(set.clone(), set);
+        assert_eq!(set.into_iter().collect::<Vec<_>>(), vec![2, 3])
+    }
+}
@@ -0,0 +1,8 @@
+//! Binary trees and search trees.
+pub mod binary_tree;
+pub mod bst;
+pub mod treap;
+
+pub use binary_tree::{BinaryTree, Order};
+pub use bst::{BstMap, BstSet};
+pub use treap::{ImplicitTreap, Treap};
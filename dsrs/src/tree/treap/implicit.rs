@@ -0,0 +1,377 @@
+use super::node::{self, free_all, merge, reverse, size, split_at, Link, Node, Nodes};
+use crate::rand::XorShift;
+
+use std::fmt::{Debug, Formatter, Result};
+use std::iter::{FromIterator, FusedIterator};
+use std::marker::PhantomData;
+use std::ops::{Bound, RangeBounds};
+
+/// A sequence over a treap with implicit keys.
+///
+/// The key of each element is its position, which is never stored: it
+/// comes from the subtree sizes. So elements can be inserted or removed
+/// anywhere, and whole ranges can be moved or reversed, all in logarithmic
+/// time in expectation.
+pub struct ImplicitTreap<T> {
+    root: Link<(), T>,
+    rng: XorShift,
+    marker: PhantomData<Box<Node<(), T>>>
+}
+
+impl<T> ImplicitTreap<T> {
+    #[must_use]
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_rng(XorShift::from_entropy())
+    }
+
+    /// Sequence whose priorities are always the same for the same seed.
+    #[must_use]
+    #[inline]
+    pub const fn with_seed(seed: u64) -> Self {
+        Self::with_rng(XorShift::new(seed))
+    }
+
+    #[inline]
+    const fn with_rng(rng: XorShift) -> Self {
+        Self { root: None, rng, marker: PhantomData }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn len(&self) -> usize {
+        // SAFETY: the root is owned by the sequence
+        unsafe { size(self.root) }
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        // SAFETY: the root is owned by the sequence and it is unlinked here
+        unsafe { free_all(self.root.take()) }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        // SAFETY: the root is owned by the sequence, which is borrowed
+        unsafe { node::select(self.root, index).map(|ptr| &(*ptr.as_ptr()).value) }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        // SAFETY: the root is owned by the sequence, which is mutably
+        // borrowed
+        unsafe { node::select(self.root, index).map(|ptr| &mut (*ptr.as_ptr()).value) }
+    }
+
+    /// Inserts `value` at `index`, moving the next elements forward.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the length.
+    pub fn insert(&mut self, index: usize, value: T) {
+        assert!(index <= self.len(), "index out of bounds");
+        // SAFETY: the root is owned by the sequence and every part is linked
+        // back, with the new node between them
+        unsafe {
+            let (left, right) = split_at(self.root.take(), index);
+            let node = Node::leaf((), value, self.rng.next_u64());
+            self.root = merge(merge(left, Some(node)), right)
+        }
+    }
+
+    /// Appends `value` to the end.
+    #[inline]
+    pub fn push(&mut self, value: T) {
+        let node = Node::leaf((), value, self.rng.next_u64());
+        // SAFETY: the root is owned by the sequence
+        self.root = unsafe { merge(self.root.take(), Some(node)) }
+    }
+
+    /// Removes the element at `index`, moving the next ones back.
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.len() {
+            return None
+        }
+        // SAFETY: the root is owned by the sequence and every part is linked
+        // back, except for the removed node
+        unsafe {
+            let (left, rest) = split_at(self.root.take(), index);
+            let (target, right) = split_at(rest, 1);
+            self.root = merge(left, right);
+            target.map(|ptr| Node::free(ptr).1)
+        }
+    }
+
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        self.remove(self.len().checked_sub(1)?)
+    }
+
+    /// Reverses the order of the elements in `range`, in logarithmic time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range starts after its end or if it ends after the
+    /// length.
+    pub fn reverse<R: RangeBounds<usize>>(&mut self, range: R) {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start + 1,
+            Bound::Unbounded => 0
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end + 1,
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.len()
+        };
+        assert!(start <= end && end <= self.len(), "range out of bounds");
+
+        // SAFETY: the root is owned by the sequence and every part is linked
+        // back in the same order
+        unsafe {
+            let (left, rest) = split_at(self.root.take(), start);
+            let (middle, right) = split_at(rest, end - start);
+            reverse(middle);
+            self.root = merge(merge(left, middle), right)
+        }
+    }
+
+    /// Separates the first `at` elements from the rest, in logarithmic time.
+    ///
+    /// The second sequence gets a generator seeded by the first one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at` is greater than the length.
+    #[must_use]
+    pub fn split(mut self, at: usize) -> (Self, Self) {
+        assert!(at <= self.len(), "index out of bounds");
+        // SAFETY: the root is owned by the sequence and both parts are
+        // given to a sequence
+        let (left, right) = unsafe { split_at(self.root.take(), at) };
+        self.root = left;
+        let mut rest = Self::with_seed(self.rng.next_u64());
+        rest.root = right;
+        (self, rest)
+    }
+
+    /// Appends the elements of `other`, in logarithmic time.
+    #[must_use]
+    #[inline]
+    pub fn merge(mut self, mut other: Self) -> Self {
+        // SAFETY: both roots are owned by the sequences
+        self.root = unsafe { merge(self.root.take(), other.root.take()) };
+        self
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> {
+        // SAFETY: the root is owned by the sequence, which is borrowed
+        Iter(unsafe { Nodes::new(self.root) })
+    }
+}
+
+impl<T> Drop for ImplicitTreap<T> {
+    #[inline]
+    fn drop(&mut self) {
+        self.clear()
+    }
+}
+
+impl<T> Default for ImplicitTreap<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Debug> Debug for ImplicitTreap<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Clone> Clone for ImplicitTreap<T> {
+    /// The clone keeps the same shape and generator state.
+    #[inline]
+    fn clone(&self) -> Self {
+        let mut treap = Self::with_rng(self.rng.clone());
+        // SAFETY: the root is owned by the sequence
+        treap.root = unsafe { node::clone_all(self.root) };
+        treap
+    }
+}
+
+impl<T: PartialEq> PartialEq for ImplicitTreap<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for ImplicitTreap<T> {}
+
+impl<T> FromIterator<T> for ImplicitTreap<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut treap = Self::new();
+        treap.extend(iter);
+        treap
+    }
+}
+
+impl<T> Extend<T> for ImplicitTreap<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value)
+        }
+    }
+}
+
+// SAFETY: the nodes are only reachable from the root of the sequence, so
+// sending it moves every element along, which needs 'T: Send'
+unsafe impl<T: Send> Send for ImplicitTreap<T> {}
+// SAFETY: pending reversals are only applied through '&mut ImplicitTreap',
+// while shared access reads them without writing, giving out '&T'
+unsafe impl<T: Sync> Sync for ImplicitTreap<T> {}
+
+pub struct Iter<'a, T>(Nodes<'a, (), T>);
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a T> {
+        self.0.next().map(|node| &node.value)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.len(), Some(self.0.len()))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+impl<T> Clone for Iter<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<'a, T> IntoIterator for &'a ImplicitTreap<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+// SAFETY: 'Iter' reads the pending reversals of a borrowed sequence
+// without applying them, giving out '&T', so it needs 'T: Sync' to move
+unsafe impl<T: Sync> Send for Iter<'_, T> {}
+// SAFETY: a shared 'Iter' can only be cloned, giving out the same '&T'
+unsafe impl<T: Sync> Sync for Iter<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::super::filled;
+    use super::ImplicitTreap;
+
+    #[test]
+    fn sequence() {
+        let mut seq = filled(ImplicitTreap::with_seed(1), "treap".chars());
+        seq.insert(0, '>');
+        seq.insert(6, '<');
+        seq.insert(3, '-');
+        assert_eq!(seq.iter().collect::<String>(), ">tr-eap<");
+        assert_eq!(seq.remove(3), Some('-'));
+        assert_eq!(seq.remove(10), None);
+        assert_eq!(seq.pop(), Some('<'));
+        assert_eq!((seq.get(1), seq.get(6)), (Some(&'t'), None));
+
+        if let Some(first) = seq.get_mut(0) {
+            *first = '<'
+        }
+        seq.push('>');
+        assert_eq!(format!("{:?}", seq), "['<', 't', 'r', 'e', 'a', 'p', '>']");
+        assert_eq!(seq.len(), 7)
+    }
+
+    #[test]
+    fn collected() {
+        // the shape changes between runs, but never the order
+        let mut seq: ImplicitTreap<_> = "treap".chars().collect();
+        seq.reverse(1..4);
+        assert_eq!(seq.iter().collect::<String>(), "taerp");
+        assert_eq!(seq.len(), 5)
+    }
+
+    #[test]
+    fn reversals() {
+        let mut expected: Vec<_> = (0..200).collect();
+        let mut seq = filled(ImplicitTreap::with_seed(9), expected.iter().copied());
+
+        for i in 0..100 {
+            let (start, end) = ((i * 37) % 200, (i * 37) % 200 + (i * 13) % 50);
+            let end = end.min(200);
+            seq.reverse(start..end);
+            expected[start..end].reverse();
+            if i % 10 == 0 {
+                assert!(seq.iter().eq(&expected))
+            }
+        }
+        seq.reverse(..);
+        expected.reverse();
+        assert!(seq.iter().eq(&expected));
+        for (index, value) in expected.iter().enumerate().step_by(7) {
+            assert_eq!(seq.get(index), Some(value))
+        }
+
+        // the pending reversals are copied too
+        let copy = seq.clone();
+        assert_eq!(copy, seq);
+        seq.reverse(10..=20);
+        seq.insert(15, 1000);
+        expected[10..=20].reverse();
+        expected.insert(15, 1000);
+        assert!(seq.iter().eq(&expected));
+        assert_ne!(copy, seq)
+    }
+
+    #[test]
+    fn split_and_merge() {
+        let seq = filled(ImplicitTreap::with_seed(2), 0..10);
+        let (left, right) = seq.split(4);
+        assert!(left.iter().copied().eq(0..4));
+        assert!(right.iter().copied().eq(4..10));
+
+        // moves the first part to the end
+        let mut rotated = right.merge(left);
+        assert!(rotated.iter().copied().eq((4..10).chain(0..4)));
+        rotated.reverse(2..8);
+        assert!(rotated.iter().copied().eq(vec![4, 5, 1, 0, 9, 8, 7, 6, 2, 3]))
+    }
+
+    #[test]
+    #[should_panic]
+    fn reverse_out_of_bounds() {
+        let mut seq = filled(ImplicitTreap::with_seed(3), 0..10);
+        seq.reverse(5..11)
+    }
+}
@@ -0,0 +1,748 @@
+use super::node::{self, free_all, merge, size, split, split_at, update, Link, Node, Nodes};
+use crate::rand::XorShift;
+
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::fmt::{Debug, Formatter, Result};
+use std::iter::{FromIterator, FusedIterator};
+use std::marker::PhantomData;
+
+/// Ordered map over a treap, like `Treap` from Lab09.
+///
+/// Each node has a random priority and the tree is kept as a max-heap of
+/// them, which makes it balanced in expectation, whatever the insertion
+/// order. The priorities come from a [`XorShift`] generator, which can be
+/// seeded with [`Treap::with_seed`] for reproducible shapes.
+///
+/// Most operations are built from [`Treap::split`] and [`Treap::merge`].
+pub struct Treap<K, V> {
+    root: Link<K, V>,
+    rng: XorShift,
+    marker: PhantomData<Box<Node<K, V>>>
+}
+
+/// Both subtrees, with the entries of `a` replacing the ones with the same
+/// key in `b`.
+///
+/// The recursion goes as deep as the subtrees, which is logarithmic in
+/// expectation.
+unsafe fn unite<K: Ord, V>(a: Link<K, V>, b: Link<K, V>) -> Link<K, V> {
+    let (a, b) = match (a, b) {
+        (Some(a), Some(b)) => (a, b),
+        (rest, None) | (None, rest) => return rest
+    };
+    // SAFETY: both are valid and disjoint subtrees, owned by the caller
+    unsafe {
+        let (top, other) = if (*a.as_ptr()).priority >= (*b.as_ptr()).priority { (a, b) } else { (b, a) };
+        let key = &(*top.as_ptr()).key;
+        let (left, rest) = split(Some(other), |node| node.key < *key);
+        let (same, right) = split(rest, |node| node.key <= *key);
+
+        if let (Some(same), true) = (same, top == b) {
+            // the entry from 'a' stays
+            std::mem::swap(&mut (*same.as_ptr()).key, &mut (*b.as_ptr()).key);
+            std::mem::swap(&mut (*same.as_ptr()).value, &mut (*b.as_ptr()).value)
+        }
+        free_all(same);
+
+        let node = top.as_ptr();
+        if top == a {
+            (*node).left = unite((*node).left, left);
+            (*node).right = unite((*node).right, right)
+        } else {
+            (*node).left = unite(left, (*node).left);
+            (*node).right = unite(right, (*node).right)
+        }
+        update(top);
+        Some(top)
+    }
+}
+
+/// The entries of `a` whose keys are in `b`, if `keep` is true, or the ones
+/// whose keys aren't.
+///
+/// The recursion goes as deep as the subtrees, which is logarithmic in
+/// expectation.
+unsafe fn filter<K: Ord, V>(a: Link<K, V>, b: Link<K, V>, keep: bool) -> Link<K, V> {
+    // SAFETY: both are valid and disjoint subtrees, owned by the caller
+    unsafe {
+        let a = match (a, b) {
+            (Some(a), Some(_)) => a,
+            (None, other) | (other, None) => {
+                free_all(if keep { other } else { b });
+                return if keep { None } else { a }
+            }
+        };
+        let key = &(*a.as_ptr()).key;
+        let (left, rest) = split(b, |node| node.key < *key);
+        let (same, right) = split(rest, |node| node.key <= *key);
+        let found = same.is_some();
+        free_all(same);
+
+        let node = a.as_ptr();
+        let left = filter((*node).left, left, keep);
+        let right = filter((*node).right, right, keep);
+        if found == keep {
+            (*node).left = left;
+            (*node).right = right;
+            update(a);
+            Some(a)
+        } else {
+            Node::free(a);
+            merge(left, right)
+        }
+    }
+}
+
+impl<K, V> Treap<K, V> {
+    #[must_use]
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_rng(XorShift::from_entropy())
+    }
+
+    /// Treap whose priorities are always the same for the same seed.
+    #[must_use]
+    #[inline]
+    pub const fn with_seed(seed: u64) -> Self {
+        Self::with_rng(XorShift::new(seed))
+    }
+
+    #[inline]
+    const fn with_rng(rng: XorShift) -> Self {
+        Self { root: None, rng, marker: PhantomData }
+    }
+
+    /// Another treap with its own generator, derived from this one.
+    #[inline]
+    fn sibling(&mut self, root: Link<K, V>) -> Self {
+        let mut treap = Self::with_seed(self.rng.next_u64());
+        treap.root = root;
+        treap
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn len(&self) -> usize {
+        // SAFETY: the root is owned by the treap
+        unsafe { size(self.root) }
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        // SAFETY: the root is owned by the treap and it is unlinked here
+        unsafe { free_all(self.root.take()) }
+    }
+
+    /// The entry with the `index`-th smallest key, counting from zero.
+    #[must_use]
+    #[inline]
+    pub fn select(&self, index: usize) -> Option<(&K, &V)> {
+        // SAFETY: the root is owned by the treap
+        let ptr = unsafe { node::select(self.root, index) }?;
+        // SAFETY: same as above, and the treap is borrowed
+        let node = unsafe { &*ptr.as_ptr() };
+        Some((&node.key, &node.value))
+    }
+
+    /// The entry with the smallest key.
+    #[must_use]
+    #[inline]
+    pub fn min(&self) -> Option<(&K, &V)> {
+        self.select(0)
+    }
+
+    /// The entry with the greatest key.
+    #[must_use]
+    #[inline]
+    pub fn max(&self) -> Option<(&K, &V)> {
+        self.select(self.len().checked_sub(1)?)
+    }
+
+    /// Removes the entry at `index` in order.
+    #[inline]
+    fn remove_at(&mut self, index: usize) -> Option<(K, V)> {
+        // SAFETY: the root is owned by the treap and every part is linked
+        // back, except for the removed node
+        unsafe {
+            let (left, rest) = split_at(self.root.take(), index);
+            let (target, right) = split_at(rest, 1);
+            self.root = merge(left, right);
+            target.map(|ptr| Node::free(ptr))
+        }
+    }
+
+    #[inline]
+    pub fn pop_min(&mut self) -> Option<(K, V)> {
+        self.remove_at(0)
+    }
+
+    #[inline]
+    pub fn pop_max(&mut self) -> Option<(K, V)> {
+        self.remove_at(self.len().checked_sub(1)?)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        // SAFETY: the root is owned by the treap, which is borrowed
+        Iter(unsafe { Nodes::new(self.root) })
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys(self.iter())
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values(self.iter())
+    }
+}
+
+impl<K: Ord, V> Treap<K, V> {
+    /// Node with exactly the given key.
+    #[inline]
+    fn find<Q>(&self, key: &Q) -> Link<K, V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized
+    {
+        let mut link = self.root;
+        while let Some(ptr) = link {
+            // SAFETY: 'ptr' is a valid node owned by the treap
+            let node = unsafe { &*ptr.as_ptr() };
+            link = match key.cmp(node.key.borrow()) {
+                Ordering::Less => node.left,
+                Ordering::Equal => return Some(ptr),
+                Ordering::Greater => node.right
+            }
+        }
+        None
+    }
+
+    /// Inserts the entry, returning the old value when the key was
+    /// already present. In that case, the old key is kept.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if let Some(ptr) = self.find(&key) {
+            // SAFETY: 'ptr' is a valid node owned by the treap, which is
+            // mutably borrowed
+            return Some(std::mem::replace(unsafe { &mut (*ptr.as_ptr()).value }, value))
+        }
+        // SAFETY: the root is owned by the treap and every part is linked
+        // back, with the new node between them
+        unsafe {
+            let (left, right) = split(self.root.take(), |node| node.key < key);
+            let node = Node::leaf(key, value, self.rng.next_u64());
+            self.root = merge(merge(left, Some(node)), right)
+        }
+        None
+    }
+
+    #[inline]
+    pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized
+    {
+        self.find(key)?;
+        self.remove_at(self.rank(key))
+    }
+
+    #[inline]
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized
+    {
+        self.remove_entry(key).map(|(_, value)| value)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized
+    {
+        self.get_key_value(key).map(|(_, value)| value)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized
+    {
+        let ptr = self.find(key)?;
+        // SAFETY: 'ptr' is a valid node owned by the treap
+        let node = unsafe { &*ptr.as_ptr() };
+        Some((&node.key, &node.value))
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized
+    {
+        let ptr = self.find(key)?;
+        // SAFETY: 'ptr' is a valid node owned by the treap, which is
+        // mutably borrowed
+        Some(unsafe { &mut (*ptr.as_ptr()).value })
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized
+    {
+        self.find(key).is_some()
+    }
+
+    /// Number of keys smaller than `key`, which is the index it has or
+    /// would have in [`Treap::select`].
+    #[must_use]
+    pub fn rank<Q>(&self, key: &Q) -> usize
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized
+    {
+        let (mut link, mut rank) = (self.root, 0);
+        while let Some(ptr) = link {
+            // SAFETY: 'ptr' is a valid node owned by the treap
+            let node = unsafe { &*ptr.as_ptr() };
+            if node.key.borrow() < key {
+                // SAFETY: same as above, for its child
+                rank += unsafe { size(node.left) } + 1;
+                link = node.right
+            } else {
+                link = node.left
+            }
+        }
+        rank
+    }
+
+    /// Separates the keys smaller than `key` from the rest, in logarithmic
+    /// time.
+    ///
+    /// The second treap gets a generator seeded by the first one.
+    #[must_use]
+    pub fn split<Q>(mut self, key: &Q) -> (Self, Self)
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized
+    {
+        // SAFETY: the root is owned by the treap and both parts are given
+        // to a treap
+        let (left, right) = unsafe { split(self.root.take(), |node| node.key.borrow() < key) };
+        self.root = left;
+        let rest = self.sibling(right);
+        (self, rest)
+    }
+
+    /// Joins with a treap whose keys are all greater, in logarithmic time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if some key in `other` is not greater than every key here.
+    #[must_use]
+    pub fn merge(mut self, mut other: Self) -> Self {
+        if let (Some((last, _)), Some((first, _))) = (self.max(), other.min()) {
+            assert!(last < first, "keys should all be greater in the other treap")
+        }
+        // SAFETY: both roots are owned by the treaps, and the keys are
+        // in order
+        self.root = unsafe { merge(self.root.take(), other.root.take()) };
+        self
+    }
+
+    /// The entries in either treap. When both have the same key, the entry
+    /// from `self` is kept.
+    #[must_use]
+    #[inline]
+    pub fn union(mut self, mut other: Self) -> Self {
+        // SAFETY: both roots are owned by the treaps and are given back to
+        // 'self'
+        self.root = unsafe { unite(self.root.take(), other.root.take()) };
+        self
+    }
+
+    /// The entries whose keys are in both treaps, from `self`.
+    #[must_use]
+    #[inline]
+    pub fn intersection(mut self, mut other: Self) -> Self {
+        // SAFETY: both roots are owned by the treaps and the result is given
+        // back to 'self'
+        self.root = unsafe { filter(self.root.take(), other.root.take(), true) };
+        self
+    }
+
+    /// The entries whose keys are not in `other`.
+    #[must_use]
+    #[inline]
+    pub fn difference(mut self, mut other: Self) -> Self {
+        // SAFETY: same as above
+        self.root = unsafe { filter(self.root.take(), other.root.take(), false) };
+        self
+    }
+}
+
+impl<K, V> Drop for Treap<K, V> {
+    #[inline]
+    fn drop(&mut self) {
+        self.clear()
+    }
+}
+
+impl<K, V> Default for Treap<K, V> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Debug, V: Debug> Debug for Treap<K, V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: Clone, V: Clone> Clone for Treap<K, V> {
+    /// The clone keeps the same shape and generator state.
+    #[inline]
+    fn clone(&self) -> Self {
+        let mut treap = Self::with_rng(self.rng.clone());
+        // SAFETY: the root is owned by the treap
+        treap.root = unsafe { node::clone_all(self.root) };
+        treap
+    }
+}
+
+impl<K: PartialEq, V: PartialEq> PartialEq for Treap<K, V> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<K: Eq, V: Eq> Eq for Treap<K, V> {}
+
+impl<K: Ord, V> FromIterator<(K, V)> for Treap<K, V> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut treap = Self::new();
+        treap.extend(iter);
+        treap
+    }
+}
+
+impl<K: Ord, V> Extend<(K, V)> for Treap<K, V> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+// SAFETY: the nodes are only reachable from the root of the treap, so
+// sending it moves every entry along, which needs both to be 'Send'
+unsafe impl<K: Send, V: Send> Send for Treap<K, V> {}
+// SAFETY: splits, merges and the generator are only used through
+// '&mut Treap', so shared access only reads '&K' and '&V'
+unsafe impl<K: Sync, V: Sync> Sync for Treap<K, V> {}
+
+pub struct Iter<'a, K, V>(Nodes<'a, K, V>);
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<(&'a K, &'a V)> {
+        self.0.next().map(|node| (&node.key, &node.value))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.len(), Some(self.0.len()))
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
+
+impl<K, V> FusedIterator for Iter<'_, K, V> {}
+
+impl<K, V> Clone for Iter<'_, K, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+pub struct Keys<'a, K, V>(Iter<'a, K, V>);
+
+impl<'a, K, V> Iterator for Keys<'a, K, V> {
+    type Item = &'a K;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a K> {
+        self.0.next().map(|(key, _)| key)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<K, V> ExactSizeIterator for Keys<'_, K, V> {}
+
+impl<K, V> FusedIterator for Keys<'_, K, V> {}
+
+impl<K, V> Clone for Keys<'_, K, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+pub struct Values<'a, K, V>(Iter<'a, K, V>);
+
+impl<'a, K, V> Iterator for Values<'a, K, V> {
+    type Item = &'a V;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a V> {
+        self.0.next().map(|(_, value)| value)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<K, V> ExactSizeIterator for Values<'_, K, V> {}
+
+impl<K, V> FusedIterator for Values<'_, K, V> {}
+
+impl<K, V> Clone for Values<'_, K, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+pub struct IntoIter<K, V> {
+    treap: Treap<K, V>
+}
+
+impl<K, V> Iterator for IntoIter<K, V> {
+    type Item = (K, V);
+
+    #[inline]
+    fn next(&mut self) -> Option<(K, V)> {
+        self.treap.pop_min()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.treap.len(), Some(self.treap.len()))
+    }
+}
+
+impl<K, V> ExactSizeIterator for IntoIter<K, V> {}
+
+impl<K, V> FusedIterator for IntoIter<K, V> {}
+
+impl<K, V> IntoIterator for Treap<K, V> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V>;
+
+    #[inline]
+    fn into_iter(self) -> IntoIter<K, V> {
+        IntoIter { treap: self }
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a Treap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+
+// SAFETY: the stack of 'Iter' only points into a borrowed treap, which is
+// read without changes, giving out '&K' and '&V' that need both 'Sync'
+unsafe impl<K: Sync, V: Sync> Send for Iter<'_, K, V> {}
+// SAFETY: a shared 'Iter' can only be cloned, giving out the same entries
+unsafe impl<K: Sync, V: Sync> Sync for Iter<'_, K, V> {}
+
+#[cfg(test)]
+mod tests {
+    use super::super::filled;
+    use super::Treap;
+    use std::collections::BTreeMap;
+
+    /// Checks the heap order of the priorities and the subtree sizes,
+    /// returning the height.
+    fn check<K, V>(treap: &Treap<K, V>) -> usize {
+        let mut stack: Vec<_> = treap.root.map(|root| (root, 1)).into_iter().collect();
+        let mut height = 0;
+        while let Some((ptr, depth)) = stack.pop() {
+            // SAFETY: every node is owned by the treap
+            let node = unsafe { &*ptr.as_ptr() };
+            let mut size = 1;
+            for &child in node.left.iter().chain(&node.right) {
+                // SAFETY: same as above
+                let child_node = unsafe { &*child.as_ptr() };
+                assert!(child_node.priority <= node.priority);
+                size += child_node.size;
+                stack.push((child, depth + 1))
+            }
+            assert_eq!(node.size, size);
+            height = height.max(depth)
+        }
+        height
+    }
+
+    #[test]
+    fn ordered_entries() {
+        let mut treap = Treap::with_seed(5);
+        let mut expected = BTreeMap::new();
+        for i in 0..500_u32 {
+            let key = (i * 7919) % 1009;
+            assert_eq!(treap.insert(key, i), expected.insert(key, i))
+        }
+        for i in 0..300_u32 {
+            let key = (i * 31) % 1009;
+            assert_eq!(treap.remove(&key), expected.remove(&key))
+        }
+        check(&treap);
+        assert_eq!(treap.len(), expected.len());
+        assert!(treap.iter().eq(expected.iter()));
+        assert_eq!(treap.min(), expected.iter().next());
+        assert_eq!(treap.max(), expected.iter().next_back());
+
+        if let Some(value) = treap.get_mut(&998) {
+            *value = 0
+        }
+        assert_eq!(treap.get(&998), Some(&0));
+        assert!(!treap.contains_key(&31));
+        assert_eq!(treap.keys().len(), treap.len());
+        assert_eq!(treap.values().count(), treap.len())
+    }
+
+    #[test]
+    fn collected() {
+        // the shape changes between runs, but never the entries
+        let treap: Treap<_, _> = vec![(2, 'b'), (1, 'a'), (3, 'c'), (2, 'd')].into_iter().collect();
+        check(&treap);
+        assert_eq!(treap.len(), 3);
+        assert_eq!(treap.iter().collect::<Vec<_>>(), vec![(&1, &'a'), (&2, &'d'), (&3, &'c')])
+    }
+
+    #[test]
+    fn order_statistics() {
+        let treap = filled(Treap::with_seed(1), (0..100).map(|i| (i * 3, ())));
+        for (index, (key, ())) in treap.iter().enumerate() {
+            assert_eq!(treap.rank(key), index);
+            assert_eq!(treap.select(index), Some((key, &())))
+        }
+        assert_eq!(treap.rank(&-1), 0);
+        assert_eq!(treap.rank(&31), 11);
+        assert_eq!(treap.select(100), None)
+    }
+
+    #[test]
+    fn extract_minimum() {
+        // like 'tira_minimo' from Lab09, but from both ends
+        let mut treap = filled(Treap::with_seed(2), vec![(3, 'c'), (1, 'a'), (4, 'd'), (2, 'b'), (5, 'e')]);
+        assert_eq!(treap.pop_min(), Some((1, 'a')));
+        assert_eq!(treap.pop_max(), Some((5, 'e')));
+        check(&treap);
+        assert_eq!(format!("{:?}", treap), "{2: 'b', 3: 'c', 4: 'd'}");
+        assert_eq!(treap.clone(), treap);
+        assert_eq!(treap.into_iter().collect::<Vec<_>>(), vec![(2, 'b'), (3, 'c'), (4, 'd')]);
+
+        let mut empty: Treap<i32, ()> = Treap::with_seed(2);
+        assert_eq!((empty.pop_min(), empty.pop_max()), (None, None))
+    }
+
+    #[test]
+    fn split_and_merge() {
+        let treap = filled(Treap::with_seed(3), (0..50).map(|i| (i, i * i)));
+        let (small, large) = treap.split(&20);
+        check(&small);
+        check(&large);
+        assert!(small.keys().copied().eq(0..20));
+        assert!(large.keys().copied().eq(20..50));
+
+        let (middle, large) = large.split(&30);
+        let treap = small.merge(large);
+        assert_eq!(treap.len(), 40);
+        assert_eq!(treap.get(&25), None);
+        check(&treap);
+        assert!(middle.values().copied().eq((20..30).map(|i| i * i)))
+    }
+
+    #[test]
+    #[should_panic]
+    fn merge_out_of_order() {
+        let a = filled(Treap::with_seed(4), (0..10).map(|i| (i, ())));
+        let b = filled(Treap::with_seed(5), (5..15).map(|i| (i, ())));
+        drop(a.merge(b))
+    }
+
+    #[test]
+    fn set_operations() {
+        let evens = || filled(Treap::with_seed(6), (0..60).step_by(2).map(|i| (i, 'e')));
+        let triples = || filled(Treap::with_seed(7), (0..60).step_by(3).map(|i| (i, 't')));
+        let keys = |treap: &Treap<i32, char>| treap.keys().copied().collect::<Vec<_>>();
+
+        let union = evens().union(triples());
+        check(&union);
+        assert_eq!(keys(&union), (0..60).filter(|i| i % 2 == 0 || i % 3 == 0).collect::<Vec<_>>());
+        assert_eq!(union.get(&6), Some(&'e'));
+        assert_eq!(union.get(&9), Some(&'t'));
+        assert_eq!(triples().union(evens()).get(&6), Some(&'t'));
+
+        let both = evens().intersection(triples());
+        check(&both);
+        assert_eq!(keys(&both), (0..60).step_by(6).collect::<Vec<_>>());
+        assert!(both.values().all(|&value| value == 'e'));
+
+        let only = evens().difference(triples());
+        check(&only);
+        assert_eq!(keys(&only), (0..60).filter(|i| i % 2 == 0 && i % 3 != 0).collect::<Vec<_>>());
+        assert!(evens().difference(evens()).is_empty());
+        assert!(evens().intersection(Treap::with_seed(8)).is_empty());
+        assert_eq!(Treap::with_seed(8).union(evens()).len(), 30)
+    }
+
+    #[test]
+    fn seeded_shape() {
+        let build = |seed| filled(Treap::with_seed(seed), (0..1000).map(|i| (i, ())));
+        // SAFETY: the root is owned by the treap
+        let root = |treap: &Treap<_, _>| treap.root.map(|root| unsafe { (*root.as_ptr()).key });
+        assert_eq!(root(&build(3)), root(&build(3)));
+        // sorted insertions, but still about 2 ln(1000) levels
+        assert!((10..40).contains(&check(&build(3))))
+    }
+}
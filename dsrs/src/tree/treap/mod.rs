@@ -0,0 +1,14 @@
+mod node;
+pub mod map;
+pub mod implicit;
+
+pub use map::Treap;
+pub use implicit::ImplicitTreap;
+
+/// Fills an empty treap, so tests can build it from a fixed seed and get
+/// the same shape on every run.
+#[cfg(test)]
+fn filled<C: Extend<T>, T, I: IntoIterator<Item = T>>(mut empty: C, items: I) -> C {
+    empty.extend(items);
+    empty
+}
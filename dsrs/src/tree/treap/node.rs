@@ -0,0 +1,314 @@
+use crate::vec::Vector;
+
+use mem::ptr::NonNull;
+use std::cmp::Ordering;
+use std::marker::PhantomData;
+
+pub(super) type Link<K, V> = Option<NonNull<Node<K, V>>>;
+
+pub(super) struct Node<K, V> {
+    pub key: K,
+    pub value: V,
+    /// Greater priorities stay above, like a max-heap.
+    pub priority: u64,
+    /// Number of nodes in this subtree, including itself.
+    pub size: usize,
+    /// Whether the order of this subtree should be read backwards, pending
+    /// to be pushed down to the children.
+    pub reversed: bool,
+    pub left: Link<K, V>,
+    pub right: Link<K, V>
+}
+
+impl<K, V> Node<K, V> {
+    /// Leaks a new node without children, so it can be linked.
+    #[inline]
+    pub fn leaf(key: K, value: V, priority: u64) -> NonNull<Self> {
+        let node = Box::new(Self { key, value, priority, size: 1, reversed: false, left: None, right: None });
+        // SAFETY: boxes are never null
+        unsafe { NonNull::new_unchecked(Box::into_raw(node)) }
+    }
+
+    /// Drops a node created by [`Node::leaf`], returning its entry.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have been created by [`Node::leaf`], it must be unlinked
+    /// and must not be used after this.
+    #[inline]
+    pub unsafe fn free(ptr: NonNull<Self>) -> (K, V) {
+        // SAFETY: the caller guarantees this is a leaked box
+        let node = unsafe { Box::from_raw(ptr.as_ptr()) };
+        (node.key, node.value)
+    }
+}
+
+// Every function below requires the links it receives to be the roots of
+// valid and disjoint subtrees, whose nodes are owned by the caller.
+
+/// Number of nodes under `link`.
+#[inline]
+pub(super) unsafe fn size<K, V>(link: Link<K, V>) -> usize {
+    // SAFETY: guaranteed by the caller
+    link.map_or(0, |ptr| unsafe { (*ptr.as_ptr()).size })
+}
+
+/// Recomputes the size of a node from its children.
+#[inline]
+pub(super) unsafe fn update<K, V>(ptr: NonNull<Node<K, V>>) {
+    // SAFETY: guaranteed by the caller
+    unsafe {
+        let node = ptr.as_ptr();
+        (*node).size = 1 + size((*node).left) + size((*node).right)
+    }
+}
+
+/// Applies a pending reversal to the children of a node.
+#[inline]
+unsafe fn push_down<K, V>(ptr: NonNull<Node<K, V>>) {
+    // SAFETY: guaranteed by the caller
+    unsafe {
+        let node = &mut *ptr.as_ptr();
+        if node.reversed {
+            std::mem::swap(&mut node.left, &mut node.right);
+            for child in node.left.iter().chain(&node.right) {
+                (*child.as_ptr()).reversed ^= true
+            }
+            node.reversed = false
+        }
+    }
+}
+
+/// Marks the whole subtree to be read backwards.
+#[inline]
+pub(super) unsafe fn reverse<K, V>(link: Link<K, V>) {
+    if let Some(ptr) = link {
+        // SAFETY: guaranteed by the caller
+        unsafe { (*ptr.as_ptr()).reversed ^= true }
+    }
+}
+
+/// Splits a subtree in the nodes that go `before` and the ones that don't.
+///
+/// The nodes are visited from the root down, each one after its ancestors,
+/// and a node before the split must have all its left subtree before it
+/// too. Goes as deep as the height of the subtree, without recursion.
+pub(super) unsafe fn split<K, V, F>(mut link: Link<K, V>, mut before: F) -> (Link<K, V>, Link<K, V>)
+where
+    F: FnMut(&Node<K, V>) -> bool
+{
+    let (mut left, mut right) = (None, None);
+    // where the next node of each side should be linked
+    let (mut left_end, mut right_end): (*mut Link<K, V>, *mut Link<K, V>) = (&mut left, &mut right);
+    let mut path = Vector::new();
+
+    // SAFETY: guaranteed by the caller, and the ends are either the locals
+    // or links of nodes in the path
+    unsafe {
+        while let Some(ptr) = link {
+            push_down(ptr);
+            path.push(ptr);
+            let node = ptr.as_ptr();
+            if before(&*node) {
+                *left_end = Some(ptr);
+                left_end = &mut (*node).right;
+                link = (*node).right
+            } else {
+                *right_end = Some(ptr);
+                right_end = &mut (*node).left;
+                link = (*node).left
+            }
+        }
+        *left_end = None;
+        *right_end = None;
+        // children go after their parents in the path
+        for &ptr in path.iter().rev() {
+            update(ptr)
+        }
+    }
+    (left, right)
+}
+
+/// Splits a subtree after its first `index` nodes.
+#[inline]
+pub(super) unsafe fn split_at<K, V>(link: Link<K, V>, mut index: usize) -> (Link<K, V>, Link<K, V>) {
+    // SAFETY: guaranteed by the caller, and 'split' only gives nodes after
+    // pushing down their reversal
+    unsafe {
+        split(link, |node| {
+            let before = size(node.left);
+            if before < index {
+                index -= before + 1;
+                true
+            } else {
+                false
+            }
+        })
+    }
+}
+
+/// Joins two subtrees, with every node of `left` before the ones in
+/// `right`. Goes as deep as the height of the result, without recursion.
+pub(super) unsafe fn merge<K, V>(mut left: Link<K, V>, mut right: Link<K, V>) -> Link<K, V> {
+    let mut root = None;
+    // where the next node should be linked
+    let mut end: *mut Link<K, V> = &mut root;
+    let mut path = Vector::new();
+
+    // SAFETY: guaranteed by the caller, and the end is either the local or
+    // a link of a node in the path
+    unsafe {
+        loop {
+            match (left, right) {
+                (Some(l), Some(r)) if (*l.as_ptr()).priority > (*r.as_ptr()).priority => {
+                    push_down(l);
+                    path.push(l);
+                    *end = Some(l);
+                    end = &mut (*l.as_ptr()).right;
+                    left = (*l.as_ptr()).right
+                }
+                (Some(_), Some(r)) => {
+                    push_down(r);
+                    path.push(r);
+                    *end = Some(r);
+                    end = &mut (*r.as_ptr()).left;
+                    right = (*r.as_ptr()).left
+                }
+                (rest, None) | (None, rest) => {
+                    *end = rest;
+                    break
+                }
+            }
+        }
+        for &ptr in path.iter().rev() {
+            update(ptr)
+        }
+    }
+    root
+}
+
+/// The node at `index` in order, reading the pending reversals without
+/// applying them.
+pub(super) unsafe fn select<K, V>(mut link: Link<K, V>, mut index: usize) -> Link<K, V> {
+    let mut reversed = false;
+    while let Some(ptr) = link {
+        // SAFETY: guaranteed by the caller
+        let node = unsafe { &*ptr.as_ptr() };
+        reversed ^= node.reversed;
+        let (first, last) = if reversed { (node.right, node.left) } else { (node.left, node.right) };
+        // SAFETY: same as above
+        let before = unsafe { size(first) };
+        link = match index.cmp(&before) {
+            Ordering::Less => first,
+            Ordering::Equal => return Some(ptr),
+            Ordering::Greater => {
+                index -= before + 1;
+                last
+            }
+        }
+    }
+    None
+}
+
+/// Drops every node of a subtree, without any extra memory.
+pub(super) unsafe fn free_all<K, V>(mut link: Link<K, V>) {
+    while let Some(ptr) = link {
+        // SAFETY: guaranteed by the caller
+        unsafe {
+            let node = ptr.as_ptr();
+            // rotates the left child up, until there is none
+            if let Some(left) = (*node).left {
+                (*node).left = (*left.as_ptr()).right;
+                (*left.as_ptr()).right = Some(ptr);
+                link = Some(left)
+            } else {
+                link = (*node).right;
+                Node::free(ptr);
+            }
+        }
+    }
+}
+
+/// Copies each node of a subtree in pre-order, keeping the same shape.
+pub(super) unsafe fn clone_all<K: Clone, V: Clone>(link: Link<K, V>) -> Link<K, V> {
+    let mut root = None;
+    // each node to copy and the link that should point to its copy
+    let mut stack: Vector<(_, *mut Link<K, V>)> = Vector::new();
+    stack.extend(link.map(|ptr| (ptr, &mut root as *mut _)));
+
+    while let Some((ptr, end)) = stack.pop() {
+        // SAFETY: 'ptr' is guaranteed by the caller, while 'end' is the
+        // local or a link in a copy, which are all still alive
+        unsafe {
+            let node: &Node<K, V> = &*ptr.as_ptr();
+            let copy = Node::leaf(node.key.clone(), node.value.clone(), node.priority);
+            (*copy.as_ptr()).size = node.size;
+            (*copy.as_ptr()).reversed = node.reversed;
+            *end = Some(copy);
+            stack.extend(node.right.map(|ptr| (ptr, &mut (*copy.as_ptr()).right as *mut _)));
+            stack.extend(node.left.map(|ptr| (ptr, &mut (*copy.as_ptr()).left as *mut _)))
+        }
+    }
+    root
+}
+
+/// Goes through the nodes of a subtree in order, reading the pending
+/// reversals without applying them.
+pub(super) struct Nodes<'a, K, V> {
+    // the path to the next node, and whether each one is reversed
+    stack: Vector<(NonNull<Node<K, V>>, bool)>,
+    remaining: usize,
+    marker: PhantomData<&'a Node<K, V>>
+}
+
+impl<K, V> Nodes<'_, K, V> {
+    /// # Safety
+    ///
+    /// `root` must be a valid subtree, borrowed for the iterator lifetime.
+    #[inline]
+    pub unsafe fn new(root: Link<K, V>) -> Self {
+        // SAFETY: guaranteed by the caller
+        let remaining = unsafe { size(root) };
+        let mut nodes = Self { stack: Vector::new(), remaining, marker: PhantomData };
+        nodes.push_first(root, false);
+        nodes
+    }
+
+    /// Pushes the path to the first node of the subtree.
+    #[inline]
+    fn push_first(&mut self, mut link: Link<K, V>, mut reversed: bool) {
+        while let Some(ptr) = link {
+            // SAFETY: nodes are borrowed for the iterator lifetime
+            let node = unsafe { &*ptr.as_ptr() };
+            reversed ^= node.reversed;
+            self.stack.push((ptr, reversed));
+            link = if reversed { node.right } else { node.left }
+        }
+    }
+
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.remaining
+    }
+}
+
+impl<'a, K, V> Iterator for Nodes<'a, K, V> {
+    type Item = &'a Node<K, V>;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a Node<K, V>> {
+        let (ptr, reversed) = self.stack.pop()?;
+        // SAFETY: nodes are borrowed for the iterator lifetime
+        let node = unsafe { &*ptr.as_ptr() };
+        self.push_first(if reversed { node.left } else { node.right }, reversed);
+        self.remaining -= 1;
+        Some(node)
+    }
+}
+
+impl<K, V> Clone for Nodes<'_, K, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self { stack: self.stack.clone(), remaining: self.remaining, marker: PhantomData }
+    }
+}
@@ -0,0 +1,65 @@
+/// How a [`Vector`](super::Vector) changes its capacity.
+pub trait GrowthPolicy {
+    /// New capacity when `required` elements don't fit in `capacity`.
+    ///
+    /// Anything smaller than `required` is rounded up to it.
+    fn grow(&self, capacity: usize, required: usize) -> usize;
+
+    /// New capacity after a removal leaves `len` elements, if the vector
+    /// should shrink at all.
+    ///
+    /// Anything smaller than `len` is ignored.
+    #[inline]
+    fn shrink(&self, _capacity: usize, _len: usize) -> Option<usize> {
+        None
+    }
+}
+
+/// Doubles the capacity, starting with room for a few elements, and never
+/// shrinks. Just like `std::vec::Vec`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Doubling;
+
+impl Doubling {
+    const MIN_CAPACITY: usize = 4;
+}
+
+impl GrowthPolicy for Doubling {
+    #[inline]
+    fn grow(&self, capacity: usize, required: usize) -> usize {
+        capacity.saturating_mul(2).max(Self::MIN_CAPACITY).max(required)
+    }
+}
+
+/// Doubles the capacity when full and halves it when only a quarter is
+/// used, like `vetor.c` from Lab02.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Shrinking;
+
+impl GrowthPolicy for Shrinking {
+    #[inline]
+    fn grow(&self, capacity: usize, required: usize) -> usize {
+        capacity.saturating_mul(2).max(1).max(required)
+    }
+
+    #[inline]
+    fn shrink(&self, capacity: usize, len: usize) -> Option<usize> {
+        if len <= capacity / 4 {
+            Some(capacity / 2)
+        } else {
+            None
+        }
+    }
+}
+
+/// Grows by a fixed number of elements, using less memory but taking
+/// quadratic time to fill.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Linear(pub usize);
+
+impl GrowthPolicy for Linear {
+    #[inline]
+    fn grow(&self, capacity: usize, required: usize) -> usize {
+        capacity.saturating_add(self.0).max(required)
+    }
+}
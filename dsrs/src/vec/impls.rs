@@ -0,0 +1,149 @@
+use super::growth::GrowthPolicy;
+use super::vector::Vector;
+
+use std::borrow::{Borrow, BorrowMut};
+use std::fmt::{Debug, Formatter, Result};
+use std::hash::{Hash, Hasher};
+use std::iter::FromIterator;
+use std::ops::{Deref, DerefMut};
+
+impl<T, P: GrowthPolicy> Drop for Vector<T, P> {
+    #[inline]
+    fn drop(&mut self) {
+        // the buffer frees itself
+        self.clear()
+    }
+}
+
+impl<T, P: GrowthPolicy> Deref for Vector<T, P> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, P: GrowthPolicy> DerefMut for Vector<T, P> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, P: GrowthPolicy> AsRef<[T]> for Vector<T, P> {
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T, P: GrowthPolicy> AsMut<[T]> for Vector<T, P> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+impl<T, P: GrowthPolicy> Borrow<[T]> for Vector<T, P> {
+    #[inline]
+    fn borrow(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T, P: GrowthPolicy> BorrowMut<[T]> for Vector<T, P> {
+    #[inline]
+    fn borrow_mut(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+impl<T, P: GrowthPolicy + Default> Default for Vector<T, P> {
+    #[inline]
+    fn default() -> Self {
+        Self::with_policy(P::default())
+    }
+}
+
+impl<T: Debug, P: GrowthPolicy> Debug for Vector<T, P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Clone, P: GrowthPolicy + Clone> Clone for Vector<T, P> {
+    #[inline]
+    fn clone(&self) -> Self {
+        let mut vector = Self::with_policy(self.policy().clone());
+        vector.reserve_exact(self.len());
+        vector.extend_from_slice(self);
+        vector
+    }
+}
+
+impl<T: PartialEq<U>, U, P: GrowthPolicy, Q: GrowthPolicy> PartialEq<Vector<U, Q>> for Vector<T, P> {
+    #[inline]
+    fn eq(&self, other: &Vector<U, Q>) -> bool {
+        self[..] == other[..]
+    }
+}
+
+impl<T: Eq, P: GrowthPolicy> Eq for Vector<T, P> {}
+
+impl<T: Hash, P: GrowthPolicy> Hash for Vector<T, P> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self[..].hash(state)
+    }
+}
+
+impl<T, P: GrowthPolicy + Default> FromIterator<T> for Vector<T, P> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut vector = Self::default();
+        vector.extend(iter);
+        vector
+    }
+}
+
+impl<T, P: GrowthPolicy> Extend<T> for Vector<T, P> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for elem in iter {
+            self.push(elem)
+        }
+    }
+}
+
+impl<'a, T: 'a + Copy, P: GrowthPolicy> Extend<&'a T> for Vector<T, P> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{Linear, Vector};
+
+    #[test]
+    fn traits() {
+        let mut vector: Vector<_> = vec![3, 1, 2].into_iter().collect();
+        vector.extend(&[5, 4]);
+        let mut other = Vector::with_policy(Linear(2));
+        other.extend(vector.iter().copied());
+
+        assert_eq!(vector, other);
+        assert_eq!(format!("{:?}", vector), "[3, 1, 2, 5, 4]");
+        let copy = other.clone();
+        assert_eq!(copy.policy(), &Linear(2));
+        assert_eq!(copy.capacity(), copy.len());
+
+        vector.sort_unstable();
+        assert_eq!(vector.as_ref(), &[1, 2, 3, 4, 5]);
+        assert_ne!(vector, other)
+    }
+}
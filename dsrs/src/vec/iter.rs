@@ -0,0 +1,135 @@
+use super::growth::GrowthPolicy;
+use super::raw::RawBuf;
+use super::vector::Vector;
+
+use std::iter::FusedIterator;
+use std::ptr;
+use std::slice;
+
+pub struct IntoIter<T> {
+    buf: RawBuf<T>,
+    // initialized elements left
+    start: usize,
+    end: usize
+}
+
+impl<T> IntoIter<T> {
+    /// Elements not yielded yet.
+    #[must_use]
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: the elements in 'start..end' are initialized
+        unsafe { slice::from_raw_parts(self.buf.as_ptr().add(self.start), self.end - self.start) }
+    }
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        if self.start == self.end {
+            return None
+        }
+        self.start += 1;
+        // SAFETY: the element is initialized and is not reachable anymore
+        Some(unsafe { self.buf.as_ptr().add(self.start - 1).read() })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.start;
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        if self.start == self.end {
+            return None
+        }
+        self.end -= 1;
+        // SAFETY: the element is initialized and is not reachable anymore
+        Some(unsafe { self.buf.as_ptr().add(self.end).read() })
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> FusedIterator for IntoIter<T> {}
+
+impl<T> Drop for IntoIter<T> {
+    #[inline]
+    fn drop(&mut self) {
+        // SAFETY: 'start' is inside the buffer
+        let start = unsafe { self.buf.as_ptr().add(self.start) };
+        let rest = ptr::slice_from_raw_parts_mut(start, self.end - self.start);
+        self.start = self.end;
+        // SAFETY: the remaining elements are owned by the iterator, and
+        // the buffer frees itself afterwards
+        unsafe { ptr::drop_in_place(rest) }
+    }
+}
+
+impl<T, P: GrowthPolicy> IntoIterator for Vector<T, P> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    #[inline]
+    fn into_iter(mut self) -> IntoIter<T> {
+        let end = std::mem::replace(&mut self.length, 0);
+        let buf = std::mem::replace(&mut self.buf, RawBuf::new());
+        IntoIter { buf, start: 0, end }
+    }
+}
+
+impl<'a, T, P: GrowthPolicy> IntoIterator for &'a Vector<T, P> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> slice::Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T, P: GrowthPolicy> IntoIterator for &'a mut Vector<T, P> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> slice::IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::Vector;
+
+    #[test]
+    fn owning_iteration() {
+        let vector: Vector<_> = (1..=6).map(|i| i.to_string()).collect();
+        let mut iter = vector.into_iter();
+        assert_eq!(iter.next().as_deref(), Some("1"));
+        assert_eq!(iter.next_back().as_deref(), Some("6"));
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.as_slice(), &["2", "3", "4", "5"]);
+        assert_eq!(iter.next().as_deref(), Some("2"));
+        // the rest is dropped with the iterator
+    }
+
+    #[test]
+    fn borrowing_iteration() {
+        let mut vector: Vector<_> = (1..=4).collect();
+        for elem in &mut vector {
+            *elem *= 10
+        }
+        let mut sum = 0;
+        for elem in &vector {
+            sum += elem
+        }
+        assert_eq!(sum, 100)
+    }
+}
@@ -0,0 +1,16 @@
+//! Contiguous growable arrays.
+mod raw;
+mod growth;
+mod vector;
+mod sorted;
+mod sorted_by_key;
+mod iter;
+mod impls;
+
+pub use growth::{Doubling, GrowthPolicy, Linear, Shrinking};
+pub use vector::Vector;
+pub use sorted::SortedVec;
+pub use sorted_by_key::SortedVecByKey;
+pub use iter::IntoIter;
+
+pub(crate) use raw::RawBuf;
@@ -0,0 +1,93 @@
+use mem::alloc::{grow, shrink, Layout};
+use mem::ptr::NonNull;
+
+use std::alloc::{handle_alloc_error, AllocRef, Global};
+use std::marker::PhantomData;
+use std::mem::{align_of, size_of};
+
+/// Uninitialized memory for `capacity` elements.
+///
+/// The buffer only manages the memory, so the owner must drop any
+/// element it writes.
+pub struct RawBuf<T> {
+    ptr: NonNull<T>,
+    capacity: usize,
+    marker: PhantomData<T>
+}
+
+impl<T> RawBuf<T> {
+    const IS_ZST: bool = size_of::<T>() == 0;
+
+    /// Empty buffer, which never allocates for zero-sized types.
+    #[inline]
+    pub const fn new() -> Self {
+        let capacity = if Self::IS_ZST { usize::MAX } else { 0 };
+        Self { ptr: NonNull::dangling(), capacity, marker: PhantomData }
+    }
+
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    #[inline]
+    pub const fn as_ptr(&self) -> *mut T {
+        self.ptr.as_ptr()
+    }
+
+    fn layout(capacity: usize) -> Layout {
+        size_of::<T>()
+            .checked_mul(capacity)
+            .and_then(|size| Layout::from_size_align(size, align_of::<T>()).ok())
+            .expect("capacity overflow")
+    }
+
+    /// Reallocates for exactly `capacity` elements, keeping the contents
+    /// that still fit.
+    pub fn resize(&mut self, capacity: usize) {
+        if Self::IS_ZST || capacity == self.capacity {
+            return
+        }
+        let (old, new) = (Self::layout(self.capacity), Self::layout(capacity));
+
+        let result = if self.capacity == 0 {
+            Global.alloc(new.inner()).map(|ptr| {
+                // SAFETY: allocations are never null
+                unsafe { NonNull::new_unchecked(ptr.cast().as_ptr()) }
+            })
+        } else if capacity == 0 {
+            // SAFETY: the buffer was allocated by 'Global' with the old
+            // layout
+            unsafe { Global.dealloc(self.ptr.cast().inner(), old.inner()) };
+            Ok(NonNull::dangling())
+        } else if capacity > self.capacity {
+            // SAFETY: same as above
+            unsafe { grow(self.ptr, old, new) }
+        } else {
+            // SAFETY: same as above
+            unsafe { shrink(self.ptr, old, new) }
+        };
+
+        match result {
+            Ok(ptr) => {
+                self.ptr = ptr;
+                self.capacity = capacity
+            },
+            Err(_) => handle_alloc_error(new.inner())
+        }
+    }
+}
+
+impl<T> Drop for RawBuf<T> {
+    #[inline]
+    fn drop(&mut self) {
+        self.resize(0)
+    }
+}
+
+// SAFETY: the buffer is only reachable through 'RawBuf', so sending it
+// moves the elements in it, which needs 'T: Send'
+unsafe impl<T: Send> Send for RawBuf<T> {}
+// SAFETY: resizing needs '&mut RawBuf', so shared access can only read,
+// which is fine across threads when 'T: Sync'
+unsafe impl<T: Sync> Sync for RawBuf<T> {}
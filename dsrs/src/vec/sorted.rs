@@ -0,0 +1,359 @@
+use super::iter::IntoIter;
+use super::vector::Vector;
+
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::fmt::{Debug, Formatter, Result};
+use std::iter::FromIterator;
+use std::ops::{Bound, Deref, Range, RangeBounds};
+use std::slice;
+
+/// Number of elements at the start of `slice` for which `before` holds,
+/// assuming it stops holding after the first `false`.
+pub(super) fn partition_point<T, P: FnMut(&T) -> bool>(slice: &[T], mut before: P) -> usize {
+    let (mut low, mut high) = (0, slice.len());
+    while low < high {
+        let middle = low + (high - low) / 2;
+        if before(&slice[middle]) {
+            low = middle + 1
+        } else {
+            high = middle
+        }
+    }
+    low
+}
+
+/// Indices of the elements of a sorted `slice` inside `range`, which is
+/// empty if the range starts after its end.
+pub(super) fn range_indices<T, Q, R, C>(slice: &[T], range: &R, compare: C) -> Range<usize>
+where
+    Q: ?Sized,
+    R: RangeBounds<Q>,
+    C: Fn(&T, &Q) -> Ordering
+{
+    let start = match range.start_bound() {
+        Bound::Included(start) => partition_point(slice, |x| compare(x, start) == Ordering::Less),
+        Bound::Excluded(start) => partition_point(slice, |x| compare(x, start) != Ordering::Greater),
+        Bound::Unbounded => 0
+    };
+    let end = match range.end_bound() {
+        Bound::Included(end) => partition_point(slice, |x| compare(x, end) != Ordering::Greater),
+        Bound::Excluded(end) => partition_point(slice, |x| compare(x, end) == Ordering::Less),
+        Bound::Unbounded => slice.len()
+    };
+    start..end.max(start)
+}
+
+/// Stable merge of two sorted vectors, where equal elements are taken from
+/// `left` first.
+pub(super) fn merge<T, C>(left: Vector<T>, right: Vector<T>, mut compare: C) -> Vector<T>
+where
+    C: FnMut(&T, &T) -> Ordering
+{
+    let mut merged = Vector::with_capacity(left.len() + right.len());
+    let (mut left, mut right) = (left.into_iter().peekable(), right.into_iter().peekable());
+    loop {
+        let take_left = match (left.peek(), right.peek()) {
+            (Some(l), Some(r)) => compare(l, r) != Ordering::Greater,
+            (Some(_), None) => true,
+            (None, Some(_)) => false,
+            (None, None) => break merged
+        };
+        merged.extend(if take_left { left.next() } else { right.next() })
+    }
+}
+
+/// A vector always kept in ascending order, searched in logarithmic time.
+///
+/// Equal elements stay in insertion order, and searches always find the
+/// first of them, like `busca_ordem` from Lab02.
+pub struct SortedVec<T: Ord> {
+    items: Vector<T>
+}
+
+impl<T: Ord> SortedVec<T> {
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self { items: Vector::new() }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self { items: Vector::with_capacity(capacity) }
+    }
+
+    /// Sorts the elements, keeping the order of equal ones.
+    #[must_use]
+    #[inline]
+    pub fn from_vector(mut items: Vector<T>) -> Self {
+        items.sort();
+        Self { items }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn into_vector(self) -> Vector<T> {
+        self.items
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        &self.items
+    }
+
+    /// Inserts after every equal element, returning its position.
+    #[inline]
+    pub fn insert(&mut self, value: T) -> usize {
+        let index = self.upper_bound(&value);
+        self.items.insert(index, value);
+        index
+    }
+
+    /// Position of the first element not less than `key`.
+    #[must_use]
+    #[inline]
+    pub fn lower_bound<Q>(&self, key: &Q) -> usize
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized
+    {
+        partition_point(&self.items, |x| x.borrow() < key)
+    }
+
+    /// Position of the first element greater than `key`.
+    #[must_use]
+    #[inline]
+    pub fn upper_bound<Q>(&self, key: &Q) -> usize
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized
+    {
+        partition_point(&self.items, |x| x.borrow() <= key)
+    }
+
+    /// Position of the first element equal to `key`.
+    #[must_use]
+    #[inline]
+    pub fn find<Q>(&self, key: &Q) -> Option<usize>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized
+    {
+        let index = self.lower_bound(key);
+        match self.items.get(index) {
+            Some(value) if value.borrow() == key => Some(index),
+            _ => None
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn contains<Q>(&self, key: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized
+    {
+        self.find(key).is_some()
+    }
+
+    /// Elements inside `range`, empty if it starts after its end.
+    #[must_use]
+    #[inline]
+    pub fn range<Q, R>(&self, range: R) -> &[T]
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+        R: RangeBounds<Q>
+    {
+        let indices = range_indices(&self.items, &range, |x: &T, bound| x.borrow().cmp(bound));
+        &self.items[indices]
+    }
+
+    /// Removes the first element equal to `key`.
+    #[inline]
+    pub fn remove_value<Q>(&mut self, key: &Q) -> Option<T>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized
+    {
+        let index = self.find(key)?;
+        self.items.remove(index)
+    }
+
+    #[inline]
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        self.items.remove(index)
+    }
+
+    /// Removes the greatest element.
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        self.items.pop()
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.items.clear()
+    }
+
+    /// Moves every element from `other`, in linear time. Equal elements
+    /// from `self` come first.
+    #[inline]
+    pub fn merge(&mut self, other: Self) {
+        let this = std::mem::replace(&mut self.items, Vector::new());
+        self.items = merge(this, other.items, Ord::cmp)
+    }
+}
+
+impl<T: Ord> Deref for SortedVec<T> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T: Ord> Default for SortedVec<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord + Debug> Debug for SortedVec<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Ord + Clone> Clone for SortedVec<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self { items: self.items.clone() }
+    }
+}
+
+impl<T: Ord> PartialEq for SortedVec<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.items == other.items
+    }
+}
+
+impl<T: Ord> Eq for SortedVec<T> {}
+
+impl<T: Ord> From<Vector<T>> for SortedVec<T> {
+    #[inline]
+    fn from(items: Vector<T>) -> Self {
+        Self::from_vector(items)
+    }
+}
+
+impl<T: Ord> FromIterator<T> for SortedVec<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from_vector(iter.into_iter().collect())
+    }
+}
+
+impl<T: Ord> Extend<T> for SortedVec<T> {
+    /// Sorts the new elements and merges them, in `O(n + m log m)` time.
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.merge(iter.into_iter().collect())
+    }
+}
+
+impl<T: Ord> IntoIterator for SortedVec<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    #[inline]
+    fn into_iter(self) -> IntoIter<T> {
+        self.items.into_iter()
+    }
+}
+
+impl<'a, T: Ord> IntoIterator for &'a SortedVec<T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> slice::Iter<'a, T> {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SortedVec;
+    use std::ops::Bound::{Excluded, Included};
+
+    #[test]
+    fn searches() {
+        let mut sorted = SortedVec::new();
+        for &value in &[5, 1, 4, 4, 9, 2, 7, 4] {
+            sorted.insert(value);
+        }
+        assert_eq!(sorted.as_slice(), &[1, 2, 4, 4, 4, 5, 7, 9]);
+
+        assert_eq!(sorted.find(&4), Some(2));
+        assert_eq!(sorted.find(&3), None);
+        assert_eq!(sorted.find(&10), None);
+        assert_eq!((sorted.lower_bound(&4), sorted.upper_bound(&4)), (2, 5));
+        assert_eq!((sorted.lower_bound(&3), sorted.upper_bound(&3)), (2, 2));
+        assert_eq!(sorted.upper_bound(&9), 8);
+
+        assert_eq!(sorted.range(2..5), &[2, 4, 4, 4]);
+        assert_eq!(sorted.range(4..=7), &[4, 4, 4, 5, 7]);
+        assert_eq!(sorted.range((Excluded(4), Included(9))), &[5, 7, 9]);
+        assert_eq!(sorted.range(..3), &[1, 2]);
+        assert!(sorted.range((Included(8), Excluded(3))).is_empty());
+
+        assert_eq!(sorted.remove_value(&4), Some(4));
+        assert_eq!(sorted.remove_value(&3), None);
+        assert_eq!(sorted.pop(), Some(9));
+        assert_eq!(sorted.as_slice(), &[1, 2, 4, 4, 5, 7])
+    }
+
+    #[test]
+    fn stable_inserts() {
+        #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+        struct Price(u32);
+        // equal prices keep their arrival order
+        let mut orders = SortedVec::new();
+        let mut ids = Vec::new();
+        for &(price, id) in &[(30, 'a'), (10, 'b'), (30, 'c'), (20, 'd'), (30, 'e')] {
+            let index = orders.insert(Price(price));
+            ids.insert(index, id)
+        }
+        assert_eq!(ids, vec!['b', 'd', 'a', 'c', 'e']);
+        assert_eq!(orders.find(&Price(30)), Some(2))
+    }
+
+    #[test]
+    fn merging() {
+        let mut left: SortedVec<_> = vec![9, 1, 5, 3].into_iter().collect();
+        let right: SortedVec<_> = vec![2, 3, 10, 0].into_iter().collect();
+        left.merge(right);
+        assert_eq!(left.as_slice(), &[0, 1, 2, 3, 3, 5, 9, 10]);
+
+        left.extend(vec![4, -1, 11]);
+        assert_eq!(left.as_slice(), &[-1, 0, 1, 2, 3, 3, 4, 5, 9, 10, 11]);
+        assert_eq!(left.len(), 11);
+        left.merge(SortedVec::new());
+        assert_eq!(left.clone().into_iter().count(), 11)
+    }
+
+    #[test]
+    fn borrowed_search() {
+        let words: SortedVec<String> = ["pear", "fig", "apple", "kiwi"].iter().map(|&w| w.to_owned()).collect();
+        assert_eq!(words.find("kiwi"), Some(2));
+        assert!(words.contains("fig"));
+        assert_eq!(words.range::<str, _>((Included("b"), Excluded("l"))), &["fig", "kiwi"])
+    }
+}
@@ -0,0 +1,237 @@
+use super::iter::IntoIter;
+use super::sorted::{merge, partition_point, range_indices};
+use super::vector::Vector;
+
+use std::fmt::{Debug, Formatter, Result};
+use std::marker::PhantomData;
+use std::ops::{Deref, RangeBounds};
+use std::slice;
+
+/// A vector kept in ascending order of `key(element)`.
+///
+/// Just like [`SortedVec`](super::SortedVec), but searched by key, so the
+/// elements themselves don't need to be ordered. Equal keys stay in
+/// insertion order.
+pub struct SortedVecByKey<T, K: Ord, F: Fn(&T) -> K> {
+    items: Vector<T>,
+    key: F,
+    marker: PhantomData<fn() -> K>
+}
+
+impl<T, K: Ord, F: Fn(&T) -> K> SortedVecByKey<T, K, F> {
+    #[must_use]
+    #[inline]
+    pub const fn new(key: F) -> Self {
+        Self { items: Vector::new(), key, marker: PhantomData }
+    }
+
+    /// Sorts the elements by key, keeping the order of equal ones.
+    #[must_use]
+    #[inline]
+    pub fn from_vector(mut items: Vector<T>, key: F) -> Self {
+        items.sort_by_key(&key);
+        Self { items, key, marker: PhantomData }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn into_vector(self) -> Vector<T> {
+        self.items
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        &self.items
+    }
+
+    /// Inserts after every element with an equal key, returning its
+    /// position.
+    #[inline]
+    pub fn insert(&mut self, value: T) -> usize {
+        let index = self.upper_bound(&(self.key)(&value));
+        self.items.insert(index, value);
+        index
+    }
+
+    /// Position of the first element whose key is not less than `key`.
+    #[must_use]
+    #[inline]
+    pub fn lower_bound(&self, key: &K) -> usize {
+        partition_point(&self.items, |x| (self.key)(x) < *key)
+    }
+
+    /// Position of the first element whose key is greater than `key`.
+    #[must_use]
+    #[inline]
+    pub fn upper_bound(&self, key: &K) -> usize {
+        partition_point(&self.items, |x| (self.key)(x) <= *key)
+    }
+
+    /// Position of the first element with the given key.
+    #[must_use]
+    #[inline]
+    pub fn find(&self, key: &K) -> Option<usize> {
+        let index = self.lower_bound(key);
+        match self.items.get(index) {
+            Some(value) if (self.key)(value) == *key => Some(index),
+            _ => None
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.find(key).is_some()
+    }
+
+    /// Elements whose key is inside `range`, empty if it starts after its
+    /// end.
+    #[must_use]
+    #[inline]
+    pub fn range<R: RangeBounds<K>>(&self, range: R) -> &[T] {
+        let indices = range_indices(&self.items, &range, |x, bound| (self.key)(x).cmp(bound));
+        &self.items[indices]
+    }
+
+    /// Removes the first element with the given key.
+    #[inline]
+    pub fn remove_key(&mut self, key: &K) -> Option<T> {
+        let index = self.find(key)?;
+        self.items.remove(index)
+    }
+
+    #[inline]
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        self.items.remove(index)
+    }
+
+    /// Removes the element with the greatest key.
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        self.items.pop()
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.items.clear()
+    }
+
+    /// Moves every element from `other`, in linear time. Equal keys from
+    /// `self` come first.
+    #[inline]
+    pub fn merge(&mut self, other: Self) {
+        let this = std::mem::replace(&mut self.items, Vector::new());
+        let key = &self.key;
+        self.items = merge(this, other.items, |a, b| key(a).cmp(&key(b)))
+    }
+}
+
+impl<T, K: Ord, F: Fn(&T) -> K> Deref for SortedVecByKey<T, K, F> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T: Debug, K: Ord, F: Fn(&T) -> K> Debug for SortedVecByKey<T, K, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Clone, K: Ord, F: Fn(&T) -> K + Clone> Clone for SortedVecByKey<T, K, F> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self { items: self.items.clone(), key: self.key.clone(), marker: PhantomData }
+    }
+}
+
+impl<T, K: Ord, F: Fn(&T) -> K> Extend<T> for SortedVecByKey<T, K, F> {
+    /// Sorts the new elements and merges them, in `O(n + m log m)` time.
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut items: Vector<T> = iter.into_iter().collect();
+        items.sort_by_key(&self.key);
+        let this = std::mem::replace(&mut self.items, Vector::new());
+        let key = &self.key;
+        self.items = merge(this, items, |a, b| key(a).cmp(&key(b)))
+    }
+}
+
+impl<T, K: Ord, F: Fn(&T) -> K> IntoIterator for SortedVecByKey<T, K, F> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    #[inline]
+    fn into_iter(self) -> IntoIter<T> {
+        self.items.into_iter()
+    }
+}
+
+impl<'a, T, K: Ord, F: Fn(&T) -> K> IntoIterator for &'a SortedVecByKey<T, K, F> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> slice::Iter<'a, T> {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SortedVecByKey;
+
+    /// Like the buy and sell orders from Lab02, with prices in cents.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Order {
+        id: u32,
+        amount: u32,
+        price: u64
+    }
+
+    fn order(id: u32, amount: u32, price: u64) -> Order {
+        Order { id, amount, price }
+    }
+
+    fn ids(orders: &[Order]) -> Vec<u32> {
+        orders.iter().map(|order| order.id).collect()
+    }
+
+    #[test]
+    fn order_book() {
+        let mut book = SortedVecByKey::new(|order: &Order| order.price);
+        assert_eq!(book.insert(order(1, 10, 1050)), 0);
+        assert_eq!(book.insert(order(2, 5, 990)), 0);
+        assert_eq!(book.insert(order(3, 7, 1050)), 2);
+        assert_eq!(book.insert(order(4, 1, 1200)), 3);
+        assert_eq!(ids(&book), vec![2, 1, 3, 4]);
+
+        // the oldest order with that price, like 'busca_ordem'
+        assert_eq!(book.find(&1050), Some(1));
+        assert_eq!(book.find(&1000), None);
+        assert_eq!(ids(book.range(1000..=1200)), vec![1, 3, 4]);
+        assert_eq!((book.lower_bound(&1100), book.upper_bound(&1050)), (3, 3));
+
+        assert_eq!(book.remove_key(&1050).map(|order| order.amount), Some(10));
+        assert_eq!(ids(&book), vec![2, 3, 4]);
+        assert!(!book.contains_key(&1000))
+    }
+
+    #[test]
+    fn merging() {
+        let by_price = |order: &Order| order.price;
+        let mut book = SortedVecByKey::new(by_price);
+        book.extend(vec![order(1, 1, 300), order(2, 1, 100)]);
+        let mut other = SortedVecByKey::new(by_price);
+        other.extend(vec![order(3, 1, 200), order(4, 1, 100)]);
+
+        book.merge(other);
+        assert_eq!(ids(&book), vec![2, 4, 3, 1]);
+        assert_eq!(book.pop().map(|order| order.id), Some(1));
+        assert_eq!(book.clone().into_iter().count(), 3)
+    }
+}
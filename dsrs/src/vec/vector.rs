@@ -0,0 +1,361 @@
+use super::growth::{Doubling, GrowthPolicy};
+use super::raw::RawBuf;
+
+use std::ptr;
+use std::slice;
+
+/// A contiguous growable array.
+///
+/// The capacity changes according to its [`GrowthPolicy`], and the buffer is
+/// reallocated in place whenever the allocator can.
+pub struct Vector<T, P: GrowthPolicy = Doubling> {
+    pub(super) buf: RawBuf<T>,
+    pub(super) length: usize,
+    policy: P
+}
+
+impl<T> Vector<T> {
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self::with_policy(Doubling)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut vector = Self::new();
+        vector.reserve_exact(capacity);
+        vector
+    }
+}
+
+impl<T, P: GrowthPolicy> Vector<T, P> {
+    #[must_use]
+    #[inline]
+    pub const fn with_policy(policy: P) -> Self {
+        Self { buf: RawBuf::new(), length: 0, policy }
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn policy(&self) -> &P {
+        &self.policy
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.length
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        self.buf.capacity()
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn as_ptr(&self) -> *const T {
+        self.buf.as_ptr()
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn as_mut_ptr(&mut self) -> *mut T {
+        self.buf.as_ptr()
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: the first 'length' elements are initialized
+        unsafe { slice::from_raw_parts(self.as_ptr(), self.length) }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: the first 'length' elements are initialized
+        unsafe { slice::from_raw_parts_mut(self.as_mut_ptr(), self.length) }
+    }
+
+    /// Room for `additional` more elements, as decided by the policy.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the capacity overflows.
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        let required = self.length.checked_add(additional).expect("capacity overflow");
+        if required > self.capacity() {
+            let capacity = self.policy.grow(self.capacity(), required);
+            self.buf.resize(capacity.max(required))
+        }
+    }
+
+    /// Room for exactly `additional` more elements, ignoring the policy.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the capacity overflows.
+    #[inline]
+    pub fn reserve_exact(&mut self, additional: usize) {
+        let required = self.length.checked_add(additional).expect("capacity overflow");
+        if required > self.capacity() {
+            self.buf.resize(required)
+        }
+    }
+
+    #[inline]
+    pub fn shrink_to_fit(&mut self) {
+        self.buf.resize(self.length)
+    }
+
+    /// Lets the policy shrink the buffer after a removal.
+    #[inline]
+    fn shrink_after_removal(&mut self) {
+        if let Some(capacity) = self.policy.shrink(self.capacity(), self.length) {
+            if self.length <= capacity && capacity < self.capacity() {
+                self.buf.resize(capacity)
+            }
+        }
+    }
+
+    #[inline]
+    pub fn push(&mut self, value: T) {
+        if self.length == self.capacity() {
+            self.reserve(1)
+        }
+        // SAFETY: there is room after the last element
+        unsafe { self.as_mut_ptr().add(self.length).write(value) };
+        self.length += 1
+    }
+
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        if self.length == 0 {
+            return None
+        }
+        self.length -= 1;
+        // SAFETY: the last element is initialized and is no longer
+        // considered part of the vector
+        let value = unsafe { self.as_ptr().add(self.length).read() };
+        self.shrink_after_removal();
+        Some(value)
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    #[inline]
+    pub fn insert(&mut self, index: usize, value: T) {
+        assert!(
+            index <= self.length,
+            "insertion index (is {}) should be <= len (is {})",
+            index,
+            self.length
+        );
+        if self.length == self.capacity() {
+            self.reserve(1)
+        }
+        // SAFETY: there is room for one more, so the shifted elements stay
+        // inside the buffer
+        unsafe {
+            let ptr = self.as_mut_ptr().add(index);
+            ptr::copy(ptr, ptr.add(1), self.length - index);
+            ptr.write(value)
+        }
+        self.length += 1
+    }
+
+    /// Removes the element at `index`, shifting all after it to the left.
+    #[must_use]
+    #[inline]
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.length {
+            return None
+        }
+        self.length -= 1;
+        // SAFETY: 'index' is initialized and is moved out before being
+        // overwritten by the shift
+        let value = unsafe {
+            let ptr = self.as_mut_ptr().add(index);
+            let value = ptr.read();
+            ptr::copy(ptr.add(1), ptr, self.length - index);
+            value
+        };
+        self.shrink_after_removal();
+        Some(value)
+    }
+
+    /// Removes the element at `index`, replacing it with the last one.
+    #[must_use]
+    #[inline]
+    pub fn swap_remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.length {
+            return None
+        }
+        let last = self.length - 1;
+        self.as_mut_slice().swap(index, last);
+        self.pop()
+    }
+
+    /// Drops every element after the first `len`.
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.length {
+            return
+        }
+        let tail = ptr::slice_from_raw_parts_mut(
+            // SAFETY: 'len' is inside the vector
+            unsafe { self.as_mut_ptr().add(len) },
+            self.length - len
+        );
+        // forget the elements first, in case a drop panics
+        self.length = len;
+        // SAFETY: the elements are initialized and not reachable anymore
+        unsafe { ptr::drop_in_place(tail) }
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.truncate(0)
+    }
+
+    #[inline]
+    pub fn extend_from_slice(&mut self, other: &[T])
+    where
+        T: Clone
+    {
+        self.reserve(other.len());
+        for value in other {
+            self.push(value.clone())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{Linear, Shrinking};
+    use super::Vector;
+
+    #[test]
+    fn edits() {
+        let mut vector = Vector::new();
+        for i in 0..5 {
+            vector.push(i)
+        }
+        vector.insert(0, -1);
+        vector.insert(6, 5);
+        vector.insert(3, 10);
+        assert_eq!(vector.as_slice(), &[-1, 0, 1, 10, 2, 3, 4, 5]);
+
+        assert_eq!(vector.remove(3), Some(10));
+        assert_eq!(vector.remove(7), None);
+        assert_eq!(vector.swap_remove(0), Some(-1));
+        assert_eq!(vector.pop(), Some(4));
+        assert_eq!(vector.as_slice(), &[5, 0, 1, 2, 3]);
+        assert_eq!(vector.len(), 5);
+
+        // through 'Deref'
+        vector.sort_unstable();
+        assert_eq!(vector[4], 5);
+        assert!(vector.contains(&2));
+
+        vector.truncate(2);
+        assert_eq!(vector.as_slice(), &[0, 1]);
+        vector.clear();
+        assert!(vector.is_empty());
+        assert_eq!(vector.pop(), None)
+    }
+
+    #[test]
+    #[should_panic]
+    fn insert_out_of_bounds() {
+        let mut vector: Vector<_> = (0..2).collect();
+        vector.insert(3, 3)
+    }
+
+    /// Capacities after pushing each element.
+    fn capacities<P: super::GrowthPolicy>(mut vector: Vector<u32, P>, count: u32) -> Vec<usize> {
+        (0..count)
+            .map(|i| {
+                vector.push(i);
+                vector.capacity()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn growth_policies() {
+        assert_eq!(capacities(Vector::new(), 9), vec![4, 4, 4, 4, 8, 8, 8, 8, 16]);
+        let linear = Vector::with_policy(Linear(3));
+        assert_eq!(capacities(linear, 7), vec![3, 3, 3, 6, 6, 6, 9]);
+        let shrinking = Vector::with_policy(Shrinking);
+        assert_eq!(capacities(shrinking, 5), vec![1, 2, 4, 4, 8]);
+
+        // halves when only a quarter is in use, like Lab02
+        let mut vector = Vector::with_policy(Shrinking);
+        vector.extend(0..16);
+        assert_eq!(vector.capacity(), 16);
+        let mut seen = Vec::new();
+        while vector.pop().is_some() {
+            seen.push(vector.capacity())
+        }
+        assert_eq!(seen, vec![16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 8, 8, 4, 2, 1])
+    }
+
+    #[test]
+    fn exact_capacity() {
+        let mut vector: Vector<u64> = Vector::with_capacity(3);
+        assert_eq!(vector.capacity(), 3);
+        vector.extend_from_slice(&[1, 2]);
+        vector.reserve_exact(10);
+        assert_eq!(vector.capacity(), 12);
+        vector.reserve(1);
+        assert_eq!(vector.capacity(), 12);
+
+        vector.shrink_to_fit();
+        assert_eq!(vector.capacity(), 2);
+        assert_eq!(vector.as_slice(), &[1, 2]);
+        vector.clear();
+        vector.shrink_to_fit();
+        assert_eq!(vector.capacity(), 0)
+    }
+
+    #[test]
+    fn zero_sized() {
+        let mut vector = Vector::new();
+        for _ in 0..1000 {
+            vector.push(())
+        }
+        assert_eq!(vector.len(), 1000);
+        assert_eq!(vector.capacity(), usize::MAX);
+        assert_eq!(vector.remove(10), Some(()));
+        assert_eq!(vector.into_iter().count(), 999)
+    }
+
+    #[test]
+    fn drops() {
+        use std::rc::Rc;
+
+        let shared = Rc::new(());
+        let mut vector = Vector::with_policy(Shrinking);
+        for _ in 0..10 {
+            vector.push(Rc::clone(&shared))
+        }
+        vector.truncate(6);
+        drop(vector.remove(0));
+        assert_eq!(Rc::strong_count(&shared), 6);
+        drop(vector);
+        assert_eq!(Rc::strong_count(&shared), 1)
+    }
+}